@@ -1,5 +1,5 @@
 use criterion::{Criterion, criterion_group, criterion_main};
-use nonneg_float::NonNegative;
+use nonneg_float::{NonNegative, NonNegativeVec};
 
 fn bench_try_new(c: &mut Criterion) {
     c.bench_function("try_nonneg 1.0", |b| {
@@ -11,5 +11,51 @@ fn bench_try_new(c: &mut Criterion) {
     });
 }
 
-criterion_group!(benches, bench_try_new);
+fn bench_validate_vec(c: &mut Criterion) {
+    let data: Vec<f64> = (0..10_000).map(|i| f64::from(i) * 0.5).collect();
+    let mut group = c.benchmark_group("validate 10k f64");
+    group.bench_function("per-element", |b| {
+        b.iter(|| {
+            let values = std::hint::black_box(data.clone());
+            let result: Vec<NonNegative<f64>> = values
+                .into_iter()
+                .map(|value| NonNegative::try_new(value).unwrap())
+                .collect();
+            std::hint::black_box(result);
+        })
+    });
+    group.bench_function("bulk", |b| {
+        b.iter(|| {
+            let values = std::hint::black_box(data.clone());
+            let result = NonNegativeVec::try_from_vec(values).unwrap();
+            std::hint::black_box(result);
+        })
+    });
+    group.finish();
+}
+
+fn bench_validate_slice(c: &mut Criterion) {
+    let data: Vec<f32> = (0..1_000_000u32).map(|i| (i % 1000) as f32 * 0.5).collect();
+    let mut group = c.benchmark_group("validate slice 1M f32");
+    group.bench_function("try_from_slice", |b| {
+        b.iter(|| {
+            let result = NonNegative::try_from_slice(std::hint::black_box(&data)).unwrap();
+            std::hint::black_box(result);
+        })
+    });
+    group.bench_function("validate_slice", |b| {
+        b.iter(|| {
+            let result = NonNegative::<f32>::validate_slice(std::hint::black_box(&data)).unwrap();
+            std::hint::black_box(result);
+        })
+    });
+    group.finish();
+}
+
+criterion_group!(
+    benches,
+    bench_try_new,
+    bench_validate_vec,
+    bench_validate_slice
+);
 criterion_main!(benches);
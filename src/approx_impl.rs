@@ -0,0 +1,58 @@
+//! `approx` support, comparing the wrapped floats.
+
+use crate::{Bounded, Bounds};
+use approx::{AbsDiffEq, RelativeEq, UlpsEq};
+use num_traits::Float;
+
+impl<T: Float + AbsDiffEq<Epsilon = T>, B: Bounds> AbsDiffEq for Bounded<T, B> {
+    type Epsilon = T;
+
+    fn default_epsilon() -> T {
+        T::default_epsilon()
+    }
+
+    fn abs_diff_eq(&self, other: &Self, epsilon: T) -> bool {
+        self.get().abs_diff_eq(&other.get(), epsilon)
+    }
+}
+
+impl<T: Float + RelativeEq<Epsilon = T>, B: Bounds> RelativeEq for Bounded<T, B> {
+    fn default_max_relative() -> T {
+        T::default_max_relative()
+    }
+
+    fn relative_eq(&self, other: &Self, epsilon: T, max_relative: T) -> bool {
+        self.get().relative_eq(&other.get(), epsilon, max_relative)
+    }
+}
+
+impl<T: Float + UlpsEq<Epsilon = T>, B: Bounds> UlpsEq for Bounded<T, B> {
+    fn default_max_ulps() -> u32 {
+        T::default_max_ulps()
+    }
+
+    fn ulps_eq(&self, other: &Self, epsilon: T, max_ulps: u32) -> bool {
+        self.get().ulps_eq(&other.get(), epsilon, max_ulps)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{NonNegative, UnitInterval};
+    use approx::{assert_abs_diff_eq, assert_relative_eq, assert_relative_ne, assert_ulps_eq};
+
+    #[test]
+    fn test_approx_eq() {
+        let a = NonNegative::new(0.1f64 + 0.2);
+        let b = NonNegative::new(0.3f64);
+        assert_ne!(a, b);
+        assert_abs_diff_eq!(a, b);
+        assert_relative_eq!(a, b);
+        assert_ulps_eq!(a, b);
+        assert_relative_ne!(a, NonNegative::new(0.31));
+        assert_abs_diff_eq!(a, NonNegative::new(0.31), epsilon = 0.02);
+
+        let p = UnitInterval::new(0.5f32);
+        assert_relative_eq!(p * p, UnitInterval::new(0.25), max_relative = 1e-6);
+    }
+}
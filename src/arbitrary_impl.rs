@@ -0,0 +1,56 @@
+//! `arbitrary` support, for fuzzing with `cargo fuzz` and similar tools.
+
+use crate::NonNegative;
+use arbitrary::{Arbitrary, Result, Unstructured};
+use num_traits::Float;
+
+/// Generates any non-negative finite value from the raw float's bits, so
+/// zero, subnormals and `MAX` are all reachable.
+///
+/// Negative inputs are mirrored, NaN maps to zero and infinity maps to
+/// `MAX`, so every input produces a value.
+impl<'a, T: Float + Arbitrary<'a>> Arbitrary<'a> for NonNegative<T> {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let value = T::arbitrary(u)?.abs();
+        if value.is_nan() {
+            Ok(Self::zero())
+        } else if value.is_infinite() {
+            Ok(Self::new_unchecked(T::max_value()))
+        } else {
+            Ok(Self::new_unchecked(value))
+        }
+    }
+
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        T::size_hint(depth)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn from_bits(bits: u64) -> NonNegative<f64> {
+        let bytes = bits.to_le_bytes();
+        NonNegative::arbitrary(&mut Unstructured::new(&bytes)).unwrap()
+    }
+
+    #[test]
+    fn test_arbitrary_covers_edge_cases() {
+        assert_eq!(from_bits(0).get(), 0.0);
+        assert_eq!(from_bits(1).get(), f64::from_bits(1));
+        assert_eq!(from_bits(f64::MAX.to_bits()).get(), f64::MAX);
+        assert_eq!(from_bits((-2.5f64).to_bits()).get(), 2.5);
+        assert!(from_bits((-0.0f64).to_bits()).get().is_sign_positive());
+        assert_eq!(from_bits(f64::NAN.to_bits()).get(), 0.0);
+        assert_eq!(from_bits(f64::NEG_INFINITY.to_bits()).get(), f64::MAX);
+    }
+
+    #[test]
+    fn test_arbitrary_f32() {
+        let bytes = [0xff; 16];
+        let mut u = Unstructured::new(&bytes);
+        let value = NonNegative::<f32>::arbitrary(&mut u).unwrap();
+        assert!(value.get().is_finite() && value.get() >= 0.0);
+    }
+}
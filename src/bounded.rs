@@ -0,0 +1,536 @@
+//! A generic wrapper for floating point values constrained to a range.
+//!
+//! [`NonNegative`](crate::NonNegative), [`Positive`](crate::Positive) and
+//! [`UnitInterval`](crate::UnitInterval) are all aliases of [`Bounded`] with
+//! a crate-provided [`Bounds`] type. New domain ranges only need a marker
+//! type implementing [`Bounds`] to get validation, ordering, hashing, serde
+//! support and the [`bounded!`](crate::bounded) macro.
+
+use crate::{NonNegativeError, ParseNonNegativeError, SliceError};
+use core::cmp::Ordering;
+use core::fmt;
+use core::hash::{Hash, Hasher};
+use core::marker::PhantomData;
+use core::num::ParseFloatError;
+use core::ops::Bound;
+use core::str::FromStr;
+use num_traits::Float;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Deserializer, Serialize, Serializer, de::Unexpected};
+
+/// Describes the range accepted by a [`Bounded`] value.
+///
+/// Bounds are expressed as `f64` and compared against the value converted to
+/// `f64`. Infinities and NaN are always rejected, whatever the bounds.
+///
+/// # Examples
+///
+/// ```
+/// use nonneg_float::{Bounded, Bounds};
+/// use std::ops::Bound;
+///
+/// struct DegreeBounds;
+///
+/// impl Bounds for DegreeBounds {
+///     const LOWER: Bound<f64> = Bound::Included(0.0);
+///     const UPPER: Bound<f64> = Bound::Excluded(360.0);
+///     const NAME: &'static str = "Degrees";
+///     const MESSAGE: &'static str = "Value must be in [0, 360)";
+///     const EXPECTING: &'static str = "an angle in [0, 360)";
+/// }
+///
+/// type Degrees<T> = Bounded<T, DegreeBounds>;
+///
+/// assert_eq!(Degrees::try_new(90.0f64).unwrap().get(), 90.0);
+/// assert!(Degrees::try_new(360.0f64).is_err());
+/// ```
+pub trait Bounds {
+    /// The lower bound of the range.
+    const LOWER: Bound<f64>;
+    /// The upper bound of the range.
+    const UPPER: Bound<f64>;
+    /// Type name printed by `Debug`.
+    const NAME: &'static str = "Bounded";
+    /// Panic message used by `new` and `new_const`.
+    const MESSAGE: &'static str = "Value must be within bounds and finite";
+    /// Description of a valid value used in deserialization errors.
+    const EXPECTING: &'static str = "a finite float within bounds";
+
+    /// Returns the error for a value below the lower bound.
+    fn below(value: f64) -> NonNegativeError {
+        NonNegativeError::BelowMinimum(value)
+    }
+
+    /// Returns the error for a value above the upper bound.
+    fn above(value: f64) -> NonNegativeError {
+        NonNegativeError::AboveMaximum(value)
+    }
+}
+
+/// Returns whether `value` lies below `lower`.
+const fn is_below(value: f64, lower: Bound<f64>) -> bool {
+    match lower {
+        Bound::Included(min) => value < min,
+        Bound::Excluded(min) => value <= min,
+        Bound::Unbounded => false,
+    }
+}
+
+/// Returns whether `value` lies above `upper`.
+const fn is_above(value: f64, upper: Bound<f64>) -> bool {
+    match upper {
+        Bound::Included(max) => value > max,
+        Bound::Excluded(max) => value >= max,
+        Bound::Unbounded => false,
+    }
+}
+
+/// Converts a float to `f64` for bound checks and error reporting.
+fn to_f64<T: Float>(value: T) -> f64 {
+    value.to_f64().unwrap_or(f64::NAN)
+}
+
+/// Wrapper type guaranteeing a finite floating-point value within the range
+/// described by `B`.
+///
+/// Since NaN is excluded, `Bounded<T, B>` implements `Eq`, `Ord` and `Hash`,
+/// so it can be sorted and used as a `BTreeMap` or `HashMap` key. Negative
+/// zero is normalised to `0.0` on construction, keeping `Hash` consistent
+/// with `Eq`.
+///
+/// With the `serde` feature enabled the value serializes as the bare float,
+/// and deserialization goes through [`Bounded::try_new`] so invalid input
+/// is rejected rather than wrapped.
+///
+/// The type is `repr(transparent)` over `T`, so slices can be reinterpreted
+/// without copying via [`Bounded::as_slice_of_floats`] and
+/// [`Bounded::try_from_slice`].
+#[repr(transparent)]
+pub struct Bounded<T: Float, B: Bounds>(T, PhantomData<B>);
+
+impl<T: Float, B: Bounds> Bounded<T, B> {
+    /// Wraps a value without checking it.
+    ///
+    /// Callers must ensure the value is finite, within `B` and not negative
+    /// zero.
+    pub(crate) const fn new_unchecked(value: T) -> Self {
+        Self(value, PhantomData)
+    }
+
+    /// Returns a reference to the wrapped value.
+    #[cfg(feature = "diesel")]
+    pub(crate) const fn get_ref(&self) -> &T {
+        &self.0
+    }
+
+    /// Attempts to create a new `Bounded<T, B>` from a value.
+    ///
+    /// Negative zero is accepted and stored as `0.0`.
+    ///
+    /// Returns `Err` if the value is outside `B` or not finite.
+    pub fn try_new(value: T) -> Result<Self, NonNegativeError> {
+        if value.is_nan() {
+            return Err(NonNegativeError::NaN);
+        }
+        let raw = to_f64(value);
+        if value.is_infinite() {
+            Err(NonNegativeError::Infinite(raw))
+        } else if is_below(raw, B::LOWER) {
+            Err(B::below(raw))
+        } else if is_above(raw, B::UPPER) {
+            Err(B::above(raw))
+        } else if value == T::zero() {
+            Ok(Self::new_unchecked(T::zero()))
+        } else {
+            Ok(Self::new_unchecked(value))
+        }
+    }
+
+    /// Creates a new `Bounded<T, B>` or panics if invalid.
+    ///
+    /// # Panics
+    ///
+    /// Panics with `B::MESSAGE` if the value is outside `B` or not finite.
+    pub fn new(value: T) -> Self {
+        Self::try_new(value).expect(B::MESSAGE)
+    }
+
+    /// Returns the inner float value.
+    pub fn get(&self) -> T {
+        self.0
+    }
+
+    /// Checks that `value` can be reinterpreted as `Bounded<T, B>` as is.
+    ///
+    /// Stricter than `try_new`: negative zero is rejected, as it cannot be
+    /// normalised in place.
+    pub(crate) fn validate_in_place(value: T) -> Result<(), NonNegativeError> {
+        if value == T::zero() && value.is_sign_negative() {
+            return Err(NonNegativeError::Negative(-0.0));
+        }
+        Self::try_new(value).map(|_| ())
+    }
+
+    /// Views a slice of bounded values as a slice of raw floats, without
+    /// copying.
+    pub fn as_slice_of_floats(values: &[Self]) -> &[T] {
+        // SAFETY: `Bounded<T, B>` is `repr(transparent)` over `T`.
+        unsafe { core::slice::from_raw_parts(values.as_ptr().cast::<T>(), values.len()) }
+    }
+
+    /// Views a slice of raw floats as bounded values, without copying.
+    ///
+    /// Returns `Err` with the index of the first invalid element. Negative
+    /// zero is rejected, as it cannot be normalised in place.
+    pub fn try_from_slice(values: &[T]) -> Result<&[Self], SliceError> {
+        for (index, &value) in values.iter().enumerate() {
+            Self::validate_in_place(value).map_err(|error| SliceError { index, error })?;
+        }
+        // SAFETY: `Bounded<T, B>` is `repr(transparent)` over `T`, and every
+        // element was checked to uphold the invariant.
+        Ok(unsafe { core::slice::from_raw_parts(values.as_ptr().cast::<Self>(), values.len()) })
+    }
+}
+
+macro_rules! impl_const_new {
+    ($($t:ty),*) => {$(
+        impl<B: Bounds> Bounded<$t, B> {
+            /// Creates a new value in a const context.
+            ///
+            /// Negative zero is stored as `0.0`, matching `try_new`.
+            ///
+            /// # Panics
+            ///
+            /// Panics with `B::MESSAGE` if the value is outside `B` or not
+            /// finite. When evaluated at compile time this is a compile
+            /// error.
+            pub const fn new_const(value: $t) -> Self {
+                let raw = value as f64;
+                if !value.is_finite() || is_below(raw, B::LOWER) || is_above(raw, B::UPPER) {
+                    panic!("{}", B::MESSAGE);
+                }
+                if value == 0.0 {
+                    Self::new_unchecked(0.0)
+                } else {
+                    Self::new_unchecked(value)
+                }
+            }
+        }
+    )*};
+}
+
+impl_const_new!(f32, f64);
+
+mod sealed {
+    pub trait Sealed {}
+
+    impl Sealed for f32 {}
+    impl Sealed for f64 {}
+}
+
+/// Float types that can be written as literals, `f32` and `f64`.
+///
+/// Lets `bounded!` check an untyped literal in const context once its type
+/// has been inferred.
+#[doc(hidden)]
+pub trait LiteralFloat: Float + sealed::Sealed {
+    const ZERO: Self;
+    const IS_F32: bool;
+}
+
+impl LiteralFloat for f32 {
+    const ZERO: Self = 0.0;
+    const IS_F32: bool = true;
+}
+
+impl LiteralFloat for f64 {
+    const ZERO: Self = 0.0;
+    const IS_F32: bool = false;
+}
+
+impl<T: LiteralFloat, B: Bounds> Bounded<T, B> {
+    /// Creates a value from a literal of inferred type, for `bounded!`. Not
+    /// public API.
+    ///
+    /// # Panics
+    ///
+    /// Panics with `B::MESSAGE`, like `new_const`.
+    #[doc(hidden)]
+    pub const fn __new_literal(value: T) -> Self {
+        let ptr: *const T = &value;
+        // SAFETY: the sealed `LiteralFloat` is only implemented for `f32` and
+        // `f64`, and `IS_F32` says which one `T` is.
+        let raw = if T::IS_F32 {
+            (unsafe { *ptr.cast::<f32>() }) as f64
+        } else {
+            unsafe { *ptr.cast::<f64>() }
+        };
+        if !raw.is_finite() || is_below(raw, B::LOWER) || is_above(raw, B::UPPER) {
+            panic!("{}", B::MESSAGE);
+        }
+        if raw == 0.0 {
+            Self::new_unchecked(T::ZERO)
+        } else {
+            Self::new_unchecked(value)
+        }
+    }
+}
+
+#[cfg(feature = "half")]
+macro_rules! impl_half_const_new {
+    ($($t:ident),*) => {$(
+        impl<B: Bounds> Bounded<half::$t, B> {
+            /// Creates a new value in a const context.
+            ///
+            /// Takes an `f64`, as Rust has no half-precision literals, so
+            /// `bounded!` and `nonneg!` accept plain literals. Both the value
+            /// and its rounding to the nearest representable value are
+            /// checked. Negative zero is stored as `0.0`.
+            ///
+            /// # Panics
+            ///
+            /// Panics with `B::MESSAGE` if either the value or its rounding
+            /// is outside `B` or not finite. When evaluated at compile time
+            /// this is a compile error.
+            pub const fn new_const(value: f64) -> Self {
+                let rounded = half::$t::from_f64_const(value);
+                let raw = rounded.to_f64_const();
+                if !rounded.is_finite()
+                    || is_below(value, B::LOWER)
+                    || is_above(value, B::UPPER)
+                    || is_below(raw, B::LOWER)
+                    || is_above(raw, B::UPPER)
+                {
+                    panic!("{}", B::MESSAGE);
+                }
+                if raw == 0.0 {
+                    Self::new_unchecked(half::$t::ZERO)
+                } else {
+                    Self::new_unchecked(rounded)
+                }
+            }
+        }
+    )*};
+}
+
+#[cfg(feature = "half")]
+impl_half_const_new!(f16, bf16);
+
+impl<T: Float, B: Bounds> Clone for Bounded<T, B> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T: Float, B: Bounds> Copy for Bounded<T, B> {}
+
+impl<T: Float + fmt::Debug, B: Bounds> fmt::Debug for Bounded<T, B> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple(B::NAME).field(&self.0).finish()
+    }
+}
+
+impl<T: Float + fmt::Display, B: Bounds> fmt::Display for Bounded<T, B> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl<T: Float, B: Bounds> PartialEq for Bounded<T, B> {
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+impl<T: Float, B: Bounds> Eq for Bounded<T, B> {}
+
+impl<T: Float, B: Bounds> PartialOrd for Bounded<T, B> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T: Float, B: Bounds> Ord for Bounded<T, B> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // The invariant excludes NaN, so `partial_cmp` always succeeds.
+        self.0.partial_cmp(&other.0).unwrap_or(Ordering::Equal)
+    }
+}
+
+impl<T: Float, B: Bounds> Hash for Bounded<T, B> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.integer_decode().hash(state);
+    }
+}
+
+/// Parses a value, ignoring surrounding whitespace.
+///
+/// Accepts the same syntax as the float's own `FromStr`, such as `"1e-3"`.
+/// Values that parse but fall outside `B`, including `"inf"` and `"NaN"`,
+/// are reported as [`ParseNonNegativeError::Invalid`].
+impl<T, B> FromStr for Bounded<T, B>
+where
+    T: Float + FromStr<Err = ParseFloatError>,
+    B: Bounds,
+{
+    type Err = ParseNonNegativeError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let value: T = s.trim().parse()?;
+        Ok(Self::try_new(value)?)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<T, B> Serialize for Bounded<T, B>
+where
+    T: Float + Serialize,
+    B: Bounds,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.0.serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, T, B> Deserialize<'de> for Bounded<T, B>
+where
+    T: Float + Deserialize<'de>,
+    B: Bounds,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let value = T::deserialize(deserializer)?;
+        Self::try_new(value).map_err(|err| {
+            serde::de::Error::invalid_value(Unexpected::Float(err.value()), &B::EXPECTING)
+        })
+    }
+}
+
+/// Macro to create a [`Bounded`] value of the given type.
+///
+/// Usage:
+/// - `bounded!(Type, literal)` checks the literal at compile time (`Type`
+///   must wrap `f32` or `f64`, or `f16`/`bf16` with the `half` feature).
+/// - `bounded!(Type, value)` returns `Result<Type, NonNegativeError>`.
+///
+/// ```
+/// use nonneg_float::{NonNegative, bounded};
+///
+/// const RATE: NonNegative<f64> = bounded!(NonNegative<f64>, 0.5);
+/// let x = 2.0;
+/// assert_eq!(bounded!(NonNegative<f64>, x).unwrap().get(), 2.0);
+/// # assert_eq!(RATE.get(), 0.5);
+/// ```
+#[macro_export]
+macro_rules! bounded {
+    // Untyped literals: the float type comes from the literal's suffix or
+    // the surrounding context, falling back to `f64`. The named const checks
+    // the value as `f64`, which `cargo check` already reports. The inline
+    // const then checks it in the inferred type, catching for example an
+    // `f32` that rounds to zero, but is only evaluated by a full build.
+    (@literal $b:ty, $val:expr) => {{
+        const _: $crate::Bounded<f64, $b> = $crate::Bounded::<f64, $b>::new_const($val as f64);
+        const { $crate::Bounded::<_, $b>::__new_literal($val) }
+    }};
+    // A leading `-` is matched separately, as the `literal` fragment would
+    // otherwise reject `bounded!(T, -x)` instead of falling through.
+    ($t:ty, - $val:literal) => {{
+        const VALUE: $t = <$t>::new_const(-$val);
+        VALUE
+    }};
+    ($t:ty, - $($rest:tt)+) => {{ <$t>::try_new(- $($rest)+) }};
+    ($t:ty, $val:literal) => {{
+        const VALUE: $t = <$t>::new_const($val);
+        VALUE
+    }};
+    ($t:ty, $val:expr) => {{ <$t>::try_new($val) }};
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct DegreeBounds;
+
+    impl Bounds for DegreeBounds {
+        const LOWER: Bound<f64> = Bound::Included(0.0);
+        const UPPER: Bound<f64> = Bound::Excluded(360.0);
+        const NAME: &'static str = "Degrees";
+    }
+
+    type Degrees<T> = Bounded<T, DegreeBounds>;
+
+    struct LatitudeBounds;
+
+    impl Bounds for LatitudeBounds {
+        const LOWER: Bound<f64> = Bound::Included(-90.0);
+        const UPPER: Bound<f64> = Bound::Included(90.0);
+    }
+
+    type Latitude<T> = Bounded<T, LatitudeBounds>;
+
+    #[test]
+    fn test_custom_bounds() {
+        assert_eq!(Degrees::try_new(0.0f64).unwrap().get(), 0.0);
+        assert_eq!(Degrees::try_new(359.5f32).unwrap().get(), 359.5);
+        assert_eq!(
+            Degrees::try_new(360.0f64).unwrap_err(),
+            NonNegativeError::AboveMaximum(360.0)
+        );
+        assert_eq!(
+            Degrees::try_new(-1.0f64).unwrap_err(),
+            NonNegativeError::BelowMinimum(-1.0)
+        );
+
+        assert_eq!(Latitude::try_new(-90.0f64).unwrap().get(), -90.0);
+        assert_eq!(Latitude::try_new(90.0f64).unwrap().get(), 90.0);
+        assert!(Latitude::try_new(90.5f64).is_err());
+        assert_eq!(
+            Latitude::try_new(f64::NEG_INFINITY).unwrap_err(),
+            NonNegativeError::Infinite(f64::NEG_INFINITY)
+        );
+        assert_eq!(
+            Latitude::try_new(f64::NAN).unwrap_err(),
+            NonNegativeError::NaN
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Value must be within bounds and finite")]
+    fn test_new_panics_with_default_message() {
+        let _ = Latitude::new(100.0f64);
+    }
+
+    #[test]
+    fn test_debug_uses_name() {
+        assert_eq!(format!("{:?}", Degrees::new(45.0f64)), "Degrees(45.0)");
+        assert_eq!(format!("{:?}", Latitude::new(1.0f64)), "Bounded(1.0)");
+    }
+
+    #[test]
+    fn test_macro() {
+        const NORTH: Latitude<f64> = bounded!(Latitude<f64>, 90.0);
+        const SOUTH: Latitude<f32> = bounded!(Latitude<f32>, -90.0);
+        assert_eq!(NORTH.get(), 90.0);
+        assert_eq!(SOUTH.get(), -90.0);
+
+        let x = 400.0;
+        assert!(bounded!(Degrees<f64>, x).is_err());
+        assert!(bounded!(Degrees<f64>, -x).is_err());
+        assert_eq!(bounded!(Degrees<f64>, x / 2.0).unwrap().get(), 200.0);
+    }
+
+    #[test]
+    fn test_ord() {
+        let mut values = [30.0f64, -45.0, 0.0].map(Latitude::new);
+        values.sort();
+        assert_eq!(values.map(|v| v.get()), [-45.0, 0.0, 30.0]);
+    }
+}
@@ -0,0 +1,67 @@
+//! `bytemuck` support for zero-copy casts of bounded values.
+
+use crate::{Bounded, Bounds, NonNegative, UnitInterval};
+use bytemuck::checked::CheckedBitPattern;
+use bytemuck::{NoUninit, Zeroable};
+use num_traits::Float;
+
+// SAFETY: zero is a valid `NonNegative`.
+unsafe impl<T: Float + Zeroable> Zeroable for NonNegative<T> {}
+
+// SAFETY: zero is a valid `UnitInterval`.
+unsafe impl<T: Float + Zeroable> Zeroable for UnitInterval<T> {}
+
+// SAFETY: `Bounded<T, B>` is `repr(transparent)` over `T`, so it has no
+// padding or uninit bytes whenever `T` has none.
+unsafe impl<T, B> NoUninit for Bounded<T, B>
+where
+    T: Float + NoUninit,
+    B: Bounds + 'static,
+{
+}
+
+// SAFETY: `Bounded<T, B>` is `repr(transparent)` over `T`, and only bit
+// patterns that uphold the invariant are accepted.
+unsafe impl<T, B> CheckedBitPattern for Bounded<T, B>
+where
+    T: Float + bytemuck::AnyBitPattern,
+    B: Bounds + 'static,
+{
+    type Bits = T;
+
+    fn is_valid_bit_pattern(bits: &T) -> bool {
+        Self::validate_in_place(*bits).is_ok()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{NonNegative, Positive};
+    use bytemuck::checked;
+
+    #[test]
+    fn test_zeroable() {
+        let zero: NonNegative<f32> = bytemuck::Zeroable::zeroed();
+        assert_eq!(zero, NonNegative::zero());
+    }
+
+    #[test]
+    fn test_cast_to_bytes_and_back() {
+        let values = [NonNegative::new(1.0f32), NonNegative::new(0.5)];
+        let bytes: &[u8] = bytemuck::cast_slice(&values);
+        assert_eq!(bytes.len(), 8);
+        let back: &[NonNegative<f32>] = checked::cast_slice(bytes);
+        assert_eq!(back, values);
+    }
+
+    #[test]
+    fn test_checked_cast_rejects_invalid() {
+        let raw = [1.0f64, -2.0];
+        let bytes: &[u8] = bytemuck::cast_slice(&raw);
+        assert!(checked::try_cast_slice::<u8, NonNegative<f64>>(bytes).is_err());
+        let bytes: &[u8] = bytemuck::cast_slice(&[-0.0f64]);
+        assert!(checked::try_cast_slice::<u8, NonNegative<f64>>(bytes).is_err());
+        let bytes: &[u8] = bytemuck::cast_slice(&[0.0f32]);
+        assert!(checked::try_cast_slice::<u8, Positive<f32>>(bytes).is_err());
+    }
+}
@@ -0,0 +1,159 @@
+//! Conversions between bounded values and primitive numbers.
+//!
+//! - `TryFrom<f32>` / `TryFrom<f64>` validate a raw float, narrowing first
+//!   when needed. Narrowing that overflows to infinity is rejected.
+//! - `f32 -> f64` widening between wrappers is infallible, and the reverse
+//!   is fallible.
+//! - Unsigned integers convert infallibly into `NonNegative` wherever the
+//!   float type represents them exactly.
+//! - `From<Bounded<T, B>>` unwraps into the primitive.
+
+use crate::{Bounded, Bounds, NonNegative, NonNegativeError};
+
+impl<B: Bounds> TryFrom<f32> for Bounded<f32, B> {
+    type Error = NonNegativeError;
+
+    fn try_from(value: f32) -> Result<Self, Self::Error> {
+        Self::try_new(value)
+    }
+}
+
+impl<B: Bounds> TryFrom<f64> for Bounded<f64, B> {
+    type Error = NonNegativeError;
+
+    fn try_from(value: f64) -> Result<Self, Self::Error> {
+        Self::try_new(value)
+    }
+}
+
+impl<B: Bounds> TryFrom<f32> for Bounded<f64, B> {
+    type Error = NonNegativeError;
+
+    fn try_from(value: f32) -> Result<Self, Self::Error> {
+        Self::try_new(f64::from(value))
+    }
+}
+
+impl<B: Bounds> TryFrom<f64> for Bounded<f32, B> {
+    type Error = NonNegativeError;
+
+    fn try_from(value: f64) -> Result<Self, Self::Error> {
+        Bounded::<f64, B>::try_new(value)?;
+        Self::try_new(value as f32)
+    }
+}
+
+impl<B: Bounds> From<Bounded<f32, B>> for Bounded<f64, B> {
+    fn from(value: Bounded<f32, B>) -> Self {
+        Self::new_unchecked(f64::from(value.get()))
+    }
+}
+
+/// Narrows to `f32`, revalidating since rounding may leave the bounds or
+/// overflow to infinity.
+impl<B: Bounds> TryFrom<Bounded<f64, B>> for Bounded<f32, B> {
+    type Error = NonNegativeError;
+
+    fn try_from(value: Bounded<f64, B>) -> Result<Self, Self::Error> {
+        Self::try_new(value.get() as f32)
+    }
+}
+
+impl<B: Bounds> From<Bounded<f32, B>> for f32 {
+    fn from(value: Bounded<f32, B>) -> Self {
+        value.get()
+    }
+}
+
+impl<B: Bounds> From<Bounded<f64, B>> for f64 {
+    fn from(value: Bounded<f64, B>) -> Self {
+        value.get()
+    }
+}
+
+impl<B: Bounds> From<Bounded<f32, B>> for f64 {
+    fn from(value: Bounded<f32, B>) -> Self {
+        f64::from(value.get())
+    }
+}
+
+macro_rules! impl_from_unsigned {
+    ($float:ty: $($int:ty),*) => {$(
+        impl From<$int> for NonNegative<$float> {
+            fn from(value: $int) -> Self {
+                Self::new_unchecked(<$float>::from(value))
+            }
+        }
+    )*};
+}
+
+impl_from_unsigned!(f32: u8, u16);
+impl_from_unsigned!(f64: u8, u16, u32);
+
+#[cfg(test)]
+mod tests {
+    use crate::{NonNegative, NonNegativeError, Positive};
+
+    #[test]
+    fn test_try_from_float() {
+        let a: NonNegative<f64> = 2.5f64.try_into().unwrap();
+        assert_eq!(a.get(), 2.5);
+        let b: NonNegative<f64> = 0.5f32.try_into().unwrap();
+        assert_eq!(b.get(), 0.5);
+        let c = NonNegative::<f32>::try_from(1.5f64).unwrap();
+        assert_eq!(c.get(), 1.5);
+
+        assert_eq!(
+            NonNegative::<f64>::try_from(-1.0f64).unwrap_err(),
+            NonNegativeError::Negative(-1.0)
+        );
+        assert_eq!(
+            Positive::<f32>::try_from(0.0f32).unwrap_err(),
+            NonNegativeError::Zero
+        );
+    }
+
+    #[test]
+    fn test_narrowing_overflow() {
+        assert_eq!(
+            NonNegative::<f32>::try_from(1e300f64).unwrap_err(),
+            NonNegativeError::Infinite(f64::INFINITY)
+        );
+        let wide = NonNegative::new(f64::MAX);
+        assert!(NonNegative::<f32>::try_from(wide).is_err());
+        let ok = NonNegative::<f32>::try_from(NonNegative::new(0.25f64)).unwrap();
+        assert_eq!(ok.get(), 0.25);
+    }
+
+    #[test]
+    fn test_narrowing_revalidates() {
+        // Rounds to zero in `f32`, which `Positive` rejects.
+        let tiny = Positive::new(1e-300f64);
+        assert_eq!(
+            Positive::<f32>::try_from(tiny).unwrap_err(),
+            NonNegativeError::Zero
+        );
+    }
+
+    #[test]
+    fn test_widening_and_unwrapping() {
+        let narrow = NonNegative::new(0.1f32);
+        let wide: NonNegative<f64> = narrow.into();
+        assert_eq!(wide.get(), f64::from(0.1f32));
+
+        let raw: f32 = narrow.into();
+        assert_eq!(raw, 0.1);
+        let raw: f64 = narrow.into();
+        assert_eq!(raw, f64::from(0.1f32));
+        let raw: f64 = wide.into();
+        assert_eq!(raw, f64::from(0.1f32));
+    }
+
+    #[test]
+    fn test_from_unsigned() {
+        assert_eq!(NonNegative::<f64>::from(u32::MAX).get(), 4294967295.0);
+        assert_eq!(NonNegative::<f32>::from(7u8).get(), 7.0);
+        let n: NonNegative<f64> = 42u16.into();
+        assert_eq!(n.get(), 42.0);
+    }
+}
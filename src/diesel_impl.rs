@@ -0,0 +1,124 @@
+//! `diesel` support: `Bounded<f32, B>` maps to `Float` and `Bounded<f64, B>`
+//! to `Double`, on any backend that supports the wrapped float.
+
+use crate::{Bounded, Bounds};
+use diesel::backend::Backend;
+use diesel::deserialize::{self, FromSql, FromSqlRow};
+use diesel::expression::AsExpression;
+use diesel::serialize::{self, Output, ToSql};
+use diesel::sql_types::{Double, Float};
+
+macro_rules! impl_diesel {
+    ($($t:ty => $sql:ty),*) => {$(
+        // `foreign_derive` implements `AsExpression` and `FromSqlRow` for the
+        // proxy's field type, so each float gets only its own SQL type.
+        const _: () = {
+            #[allow(dead_code)]
+            #[derive(AsExpression, FromSqlRow)]
+            #[diesel(foreign_derive, sql_type = $sql)]
+            struct Proxy<B: Bounds>(Bounded<$t, B>);
+        };
+
+        impl<B: Bounds, DB: Backend> ToSql<$sql, DB> for Bounded<$t, B>
+        where
+            $t: ToSql<$sql, DB>,
+        {
+            fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, DB>) -> serialize::Result {
+                self.get_ref().to_sql(out)
+            }
+        }
+
+        /// Returns the [`NonNegativeError`](crate::NonNegativeError) as the
+        /// deserialization error if the value is out of bounds.
+        impl<B: Bounds, DB: Backend> FromSql<$sql, DB> for Bounded<$t, B>
+        where
+            $t: FromSql<$sql, DB>,
+        {
+            fn from_sql(bytes: DB::RawValue<'_>) -> deserialize::Result<Self> {
+                Ok(Self::try_new(<$t>::from_sql(bytes)?)?)
+            }
+        }
+    )*};
+}
+
+impl_diesel!(f32 => Float, f64 => Double);
+
+#[cfg(test)]
+mod tests {
+    use crate::{NonNegative, NonNegativeError, Positive};
+    use diesel::prelude::*;
+    use diesel::result::{DeserializeFieldError, Error};
+    use diesel::sqlite::SqliteConnection;
+
+    diesel::table! {
+        amounts (id) {
+            id -> Integer,
+            value -> Double,
+            ratio -> Nullable<Float>,
+        }
+    }
+
+    #[derive(Debug, PartialEq, Queryable, Insertable)]
+    #[diesel(table_name = amounts)]
+    struct Amount {
+        id: i32,
+        value: NonNegative<f64>,
+        ratio: Option<Positive<f32>>,
+    }
+
+    fn connection() -> SqliteConnection {
+        let mut conn = SqliteConnection::establish(":memory:").unwrap();
+        diesel::sql_query(
+            "CREATE TABLE amounts (id INTEGER PRIMARY KEY, value DOUBLE NOT NULL, ratio FLOAT)",
+        )
+        .execute(&mut conn)
+        .unwrap();
+        conn
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let mut conn = connection();
+        let rows = [
+            Amount {
+                id: 1,
+                value: NonNegative::new(3.5),
+                ratio: Some(Positive::new(0.5)),
+            },
+            Amount {
+                id: 2,
+                value: NonNegative::zero(),
+                ratio: None,
+            },
+        ];
+        diesel::insert_into(amounts::table)
+            .values(&rows[..])
+            .execute(&mut conn)
+            .unwrap();
+
+        let loaded: Vec<Amount> = amounts::table
+            .filter(amounts::value.ge(NonNegative::new(1.0)))
+            .load(&mut conn)
+            .unwrap();
+        assert_eq!(loaded, rows[..1]);
+    }
+
+    #[test]
+    fn test_invalid_row_is_deserialization_error() {
+        let mut conn = connection();
+        diesel::sql_query("INSERT INTO amounts (id, value, ratio) VALUES (1, -1.5, NULL)")
+            .execute(&mut conn)
+            .unwrap();
+
+        let err = amounts::table.first::<Amount>(&mut conn).unwrap_err();
+        let Error::DeserializationError(source) = err else {
+            panic!("unexpected error {err:?}");
+        };
+        let field = source.downcast_ref::<DeserializeFieldError>().unwrap();
+        assert_eq!(field.field_name.as_deref(), Some("value"));
+        assert_eq!(
+            field.error.downcast_ref::<NonNegativeError>(),
+            Some(&NonNegativeError::Negative(-1.5))
+        );
+    }
+}
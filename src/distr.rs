@@ -0,0 +1,335 @@
+//! `rand` support for sampling non-negative values.
+//!
+//! - [`StandardUniform`] samples `NonNegative<T>` and `UnitInterval<T>` in
+//!   `[0, 1)`.
+//! - `NonNegative<T>` implements [`SampleUniform`], so
+//!   [`Uniform`](rand::distr::Uniform) and `Rng::random_range` work with
+//!   non-negative bounds.
+//! - [`Exp`], [`Gamma`], [`LogNormal`] and [`AbsNormal`] wrap the `rand_distr`
+//!   distributions and return `NonNegative<T>` without validating each
+//!   sample. Their samples are non-negative by construction; a sample that
+//!   overflows to infinity for extreme parameters is saturated at `T::MAX`.
+//!
+//! # Examples
+//!
+//! ```
+//! use nonneg_float::NonNegative;
+//! use nonneg_float::distr::{Exp, Gamma};
+//! use rand::Rng;
+//! use rand::distr::{Distribution, Uniform};
+//!
+//! let mut rng = rand::rng();
+//! let wait: NonNegative<f64> = Exp::new(2.0).unwrap().sample(&mut rng);
+//! let load = Gamma::new(2.0, 0.5).unwrap().sample(&mut rng);
+//! assert!(wait.get() >= 0.0 && load.get() >= 0.0);
+//!
+//! let range = Uniform::new(NonNegative::new(1.0), NonNegative::new(5.0)).unwrap();
+//! assert!((1.0..5.0).contains(&range.sample(&mut rng).get()));
+//!
+//! let unit: NonNegative<f32> = rng.random();
+//! assert!(unit.get() < 1.0);
+//! ```
+
+use crate::{NonNegative, UnitInterval};
+use core::fmt;
+use num_traits::Float;
+use rand::Rng;
+use rand::distr::uniform::{self, SampleBorrow, SampleUniform, UniformSampler};
+use rand::distr::{Distribution, StandardUniform};
+use rand_distr::{Exp1, ExpError, GammaError, Open01, StandardNormal};
+
+/// Samples in `[0, 1)`.
+impl<T: Float> Distribution<NonNegative<T>> for StandardUniform
+where
+    StandardUniform: Distribution<T>,
+{
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> NonNegative<T> {
+        NonNegative::new_unchecked(rng.sample(self))
+    }
+}
+
+/// Samples in `[0, 1)`.
+impl<T: Float> Distribution<UnitInterval<T>> for StandardUniform
+where
+    StandardUniform: Distribution<T>,
+{
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> UnitInterval<T> {
+        UnitInterval::new_unchecked(rng.sample(self))
+    }
+}
+
+/// [`UniformSampler`] for `NonNegative<T>`, delegating to the sampler of `T`.
+///
+/// Both bounds are non-negative, so every sample is too.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct UniformNonNegative<T: SampleUniform>(T::Sampler);
+
+impl<T: Float + SampleUniform> UniformSampler for UniformNonNegative<T> {
+    type X = NonNegative<T>;
+
+    fn new<B1, B2>(low: B1, high: B2) -> Result<Self, uniform::Error>
+    where
+        B1: SampleBorrow<Self::X> + Sized,
+        B2: SampleBorrow<Self::X> + Sized,
+    {
+        T::Sampler::new(low.borrow().get(), high.borrow().get()).map(Self)
+    }
+
+    fn new_inclusive<B1, B2>(low: B1, high: B2) -> Result<Self, uniform::Error>
+    where
+        B1: SampleBorrow<Self::X> + Sized,
+        B2: SampleBorrow<Self::X> + Sized,
+    {
+        T::Sampler::new_inclusive(low.borrow().get(), high.borrow().get()).map(Self)
+    }
+
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Self::X {
+        NonNegative::new_unchecked(self.0.sample(rng))
+    }
+}
+
+impl<T: Float + SampleUniform> SampleUniform for NonNegative<T> {
+    type Sampler = UniformNonNegative<T>;
+}
+
+/// Wraps a sample that is non-negative by construction, saturating
+/// overflow at `T::MAX`.
+///
+/// # Panics
+///
+/// Panics if the sample is NaN, which `Float::min` would otherwise turn
+/// into `T::MAX`.
+fn saturate<T: Float>(value: T) -> NonNegative<T> {
+    assert!(!value.is_nan(), "distribution produced a NaN sample");
+    NonNegative::new_unchecked(value.min(T::max_value()))
+}
+
+/// The exponential distribution `Exp(λ)`, sampling `NonNegative<T>`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Exp<T: Float>(rand_distr::Exp<T>)
+where
+    Exp1: Distribution<T>;
+
+impl<T: Float> Exp<T>
+where
+    Exp1: Distribution<T>,
+{
+    /// Creates `Exp(lambda)`. Returns `Err` if `lambda` is negative or NaN.
+    pub fn new(lambda: T) -> Result<Self, ExpError> {
+        rand_distr::Exp::new(lambda).map(Self)
+    }
+}
+
+impl<T: Float> Distribution<NonNegative<T>> for Exp<T>
+where
+    Exp1: Distribution<T>,
+{
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> NonNegative<T> {
+        saturate(self.0.sample(rng))
+    }
+}
+
+/// The gamma distribution `Gamma(shape, scale)`, sampling `NonNegative<T>`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Gamma<T: Float>(rand_distr::Gamma<T>)
+where
+    StandardNormal: Distribution<T>,
+    Exp1: Distribution<T>,
+    Open01: Distribution<T>;
+
+impl<T: Float> Gamma<T>
+where
+    StandardNormal: Distribution<T>,
+    Exp1: Distribution<T>,
+    Open01: Distribution<T>,
+{
+    /// Creates `Gamma(shape, scale)`. Returns `Err` if either parameter is
+    /// not positive.
+    pub fn new(shape: T, scale: T) -> Result<Self, GammaError> {
+        rand_distr::Gamma::new(shape, scale).map(Self)
+    }
+}
+
+impl<T: Float> Distribution<NonNegative<T>> for Gamma<T>
+where
+    StandardNormal: Distribution<T>,
+    Exp1: Distribution<T>,
+    Open01: Distribution<T>,
+{
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> NonNegative<T> {
+        saturate(self.0.sample(rng))
+    }
+}
+
+/// Error type returned from [`LogNormal::new`] and [`AbsNormal::new`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NormalParamError {
+    /// The mean (`mu` for [`LogNormal`]) was NaN or infinite.
+    MeanNotFinite,
+    /// The standard deviation (`sigma` for [`LogNormal`]) was NaN or
+    /// infinite.
+    BadVariance,
+}
+
+impl fmt::Display for NormalParamError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            NormalParamError::MeanNotFinite => write!(f, "mean must be finite"),
+            NormalParamError::BadVariance => write!(f, "standard deviation must be finite"),
+        }
+    }
+}
+
+impl core::error::Error for NormalParamError {}
+
+/// The log-normal distribution `ln N(mu, sigma²)`, sampling `NonNegative<T>`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LogNormal<T: Float>(rand_distr::LogNormal<T>)
+where
+    StandardNormal: Distribution<T>;
+
+impl<T: Float> LogNormal<T>
+where
+    StandardNormal: Distribution<T>,
+{
+    /// Creates `ln N(mu, sigma²)`. Returns `Err` if `mu` or `sigma` is not
+    /// finite. A negative `sigma` behaves like its absolute value.
+    pub fn new(mu: T, sigma: T) -> Result<Self, NormalParamError> {
+        if !mu.is_finite() {
+            return Err(NormalParamError::MeanNotFinite);
+        }
+        rand_distr::LogNormal::new(mu, sigma)
+            .map(Self)
+            .map_err(|_| NormalParamError::BadVariance)
+    }
+}
+
+impl<T: Float> Distribution<NonNegative<T>> for LogNormal<T>
+where
+    StandardNormal: Distribution<T>,
+{
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> NonNegative<T> {
+        saturate(self.0.sample(rng))
+    }
+}
+
+/// The absolute value of a normal distribution, `|N(mean, std_dev²)|`,
+/// sampling `NonNegative<T>`.
+///
+/// With a zero mean this is the half-normal distribution.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AbsNormal<T: Float>(rand_distr::Normal<T>)
+where
+    StandardNormal: Distribution<T>;
+
+impl<T: Float> AbsNormal<T>
+where
+    StandardNormal: Distribution<T>,
+{
+    /// Creates `|N(mean, std_dev²)|`. Returns `Err` if `mean` or `std_dev`
+    /// is not finite. A negative `std_dev` behaves like its absolute value.
+    pub fn new(mean: T, std_dev: T) -> Result<Self, NormalParamError> {
+        if !mean.is_finite() {
+            return Err(NormalParamError::MeanNotFinite);
+        }
+        rand_distr::Normal::new(mean, std_dev)
+            .map(Self)
+            .map_err(|_| NormalParamError::BadVariance)
+    }
+}
+
+impl<T: Float> Distribution<NonNegative<T>> for AbsNormal<T>
+where
+    StandardNormal: Distribution<T>,
+{
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> NonNegative<T> {
+        saturate(self.0.sample(rng).abs())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+    use rand::distr::Uniform;
+    use rand::rngs::SmallRng;
+
+    fn rng() -> SmallRng {
+        SmallRng::seed_from_u64(7)
+    }
+
+    #[test]
+    fn test_standard_uniform() {
+        let mut rng = rng();
+        for _ in 0..100 {
+            let n: NonNegative<f64> = rng.random();
+            assert!((0.0..1.0).contains(&n.get()));
+            let p: UnitInterval<f32> = rng.random();
+            assert!((0.0..1.0).contains(&p.get()));
+        }
+    }
+
+    #[test]
+    fn test_uniform_range() {
+        let mut rng = rng();
+        let low = NonNegative::new(2.0f64);
+        let high = NonNegative::new(3.0f64);
+        let range = Uniform::new_inclusive(low, high).unwrap();
+        for _ in 0..100 {
+            assert!((2.0..=3.0).contains(&range.sample(&mut rng).get()));
+            assert!((2.0..3.0).contains(&rng.random_range(low..high).get()));
+        }
+        assert!(Uniform::new(high, low).is_err());
+    }
+
+    #[test]
+    fn test_distributions() {
+        let mut rng = rng();
+        let exp = Exp::new(0.5f64).unwrap();
+        let gamma = Gamma::new(3.0f32, 2.0).unwrap();
+        let log_normal = LogNormal::new(0.0f64, 1.0).unwrap();
+        let half_normal = AbsNormal::new(0.0f64, 1.0).unwrap();
+        for _ in 0..100 {
+            assert!(exp.sample(&mut rng).get().is_finite());
+            assert!(gamma.sample(&mut rng).get().is_finite());
+            assert!(log_normal.sample(&mut rng).get() > 0.0);
+            assert!(half_normal.sample(&mut rng).get().is_sign_positive());
+        }
+        assert_eq!(Exp::new(-1.0f64), Err(ExpError::LambdaTooSmall));
+        assert_eq!(
+            AbsNormal::new(f64::INFINITY, 1.0),
+            Err(NormalParamError::MeanNotFinite)
+        );
+        assert_eq!(
+            AbsNormal::new(0.0, f64::NAN),
+            Err(NormalParamError::BadVariance)
+        );
+        assert_eq!(
+            LogNormal::new(f64::NAN, 1.0),
+            Err(NormalParamError::MeanNotFinite)
+        );
+        assert_eq!(
+            LogNormal::new(0.0, f64::INFINITY),
+            Err(NormalParamError::BadVariance)
+        );
+        let flipped = LogNormal::new(0.0f64, -1.0).unwrap();
+        assert!(flipped.sample(&mut rng).get() > 0.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "distribution produced a NaN sample")]
+    fn test_saturate_rejects_nan() {
+        let _ = saturate(f64::NAN);
+    }
+
+    #[test]
+    fn test_overflow_saturates() {
+        let mut rng = rng();
+        let huge = LogNormal::new(1e6f64, 1.0).unwrap();
+        assert_eq!(huge.sample(&mut rng).get(), f64::MAX);
+        let wide = AbsNormal::new(0.0f64, f64::MAX).unwrap();
+        for _ in 0..100 {
+            assert!(wide.sample(&mut rng).get().is_finite());
+        }
+    }
+}
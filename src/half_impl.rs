@@ -0,0 +1,201 @@
+//! Conversions for the `half` crate's `f16` and `bf16`.
+//!
+//! Both types implement `num_traits::Float`, so every `Bounded` API works
+//! with them directly. Widening into `f32` / `f64` is exact and therefore
+//! infallible. Narrowing from `f32` / `f64` revalidates, since rounding may
+//! overflow to infinity or leave the bounds.
+
+use crate::{Bounded, Bounds, NonNegativeError};
+use half::{bf16, f16};
+
+macro_rules! impl_half_conversions {
+    ($($half:ident),*) => {$(
+        impl<B: Bounds> From<Bounded<$half, B>> for Bounded<f32, B> {
+            fn from(value: Bounded<$half, B>) -> Self {
+                Self::new_unchecked(value.get().to_f32())
+            }
+        }
+
+        impl<B: Bounds> From<Bounded<$half, B>> for Bounded<f64, B> {
+            fn from(value: Bounded<$half, B>) -> Self {
+                Self::new_unchecked(value.get().to_f64())
+            }
+        }
+
+        impl<B: Bounds> TryFrom<Bounded<f32, B>> for Bounded<$half, B> {
+            type Error = NonNegativeError;
+
+            fn try_from(value: Bounded<f32, B>) -> Result<Self, Self::Error> {
+                Self::try_new($half::from_f32(value.get()))
+            }
+        }
+
+        impl<B: Bounds> TryFrom<Bounded<f64, B>> for Bounded<$half, B> {
+            type Error = NonNegativeError;
+
+            fn try_from(value: Bounded<f64, B>) -> Result<Self, Self::Error> {
+                Self::try_new($half::from_f64(value.get()))
+            }
+        }
+
+        impl<B: Bounds> From<Bounded<$half, B>> for $half {
+            fn from(value: Bounded<$half, B>) -> Self {
+                value.get()
+            }
+        }
+
+        impl<B: Bounds> From<Bounded<$half, B>> for f32 {
+            fn from(value: Bounded<$half, B>) -> Self {
+                value.get().to_f32()
+            }
+        }
+
+        impl<B: Bounds> From<Bounded<$half, B>> for f64 {
+            fn from(value: Bounded<$half, B>) -> Self {
+                value.get().to_f64()
+            }
+        }
+    )*};
+}
+
+impl_half_conversions!(f16, bf16);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{NonNegative, Positive, UnitInterval, nonneg, positive};
+
+    #[test]
+    fn test_validation() {
+        assert_eq!(
+            NonNegative::try_new(f16::from_f32(1.5)).unwrap().get(),
+            f16::from_f32(1.5)
+        );
+        assert!(
+            NonNegative::try_new(f16::NEG_ZERO)
+                .unwrap()
+                .get()
+                .is_sign_positive()
+        );
+        assert_eq!(
+            NonNegative::try_new(f16::from_f32(-2.0)).unwrap_err(),
+            NonNegativeError::Negative(-2.0)
+        );
+        assert_eq!(
+            NonNegative::try_new(bf16::NAN).unwrap_err(),
+            NonNegativeError::NaN
+        );
+        assert_eq!(
+            NonNegative::try_new(f16::INFINITY).unwrap_err(),
+            NonNegativeError::Infinite(f64::INFINITY)
+        );
+        assert_eq!(
+            UnitInterval::try_new(bf16::from_f32(1.5)).unwrap_err(),
+            NonNegativeError::GreaterThanOne(1.5)
+        );
+        assert_eq!(
+            "0.25".parse::<NonNegative<f16>>().unwrap().get(),
+            f16::from_f32(0.25)
+        );
+    }
+
+    #[test]
+    fn test_macros_and_const() {
+        const WEIGHT: NonNegative<f16> = NonNegative::<f16>::new_const(0.5);
+        assert_eq!(WEIGHT.get(), f16::from_f32(0.5));
+
+        let a = nonneg!(f16, 2.0);
+        assert_eq!(a.get(), f16::from_f32(2.0));
+        let b = nonneg!(bf16, -0.0);
+        assert!(b.get().is_sign_positive());
+        let c = positive!(bf16, 0.1);
+        assert_eq!(c.get(), bf16::from_f64(0.1));
+        assert!(nonneg!(f16, f16::from_f32(-1.0)).is_err());
+    }
+
+    #[test]
+    #[should_panic(expected = "Value must be non-negative and finite")]
+    fn test_new_const_rejects_overflow() {
+        // Larger than `f16::MAX`, so it rounds to infinity.
+        let _ = NonNegative::<f16>::new_const(1e6);
+    }
+
+    #[test]
+    #[should_panic(expected = "Value must be non-negative and finite")]
+    fn test_new_const_checks_before_rounding() {
+        // Rounds to `-0.0` in `f16`, but is negative as written.
+        let _ = NonNegative::<f16>::new_const(std::hint::black_box(-1e-10));
+    }
+
+    #[test]
+    fn test_arithmetic() {
+        let a = NonNegative::new(f16::from_f32(1.5));
+        let b = NonNegative::new(f16::from_f32(2.5));
+        assert_eq!((a + b).get(), f16::from_f32(4.0));
+        let max = NonNegative::new(f16::MAX);
+        assert!(max.checked_add(max).is_none());
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_vec() {
+        let weights =
+            crate::NonNegativeVec::try_from_vec(vec![bf16::from_f32(1.0), bf16::from_f32(3.0)])
+                .unwrap();
+        assert_eq!(weights.max().unwrap().get(), bf16::from_f32(3.0));
+    }
+
+    #[test]
+    fn test_widening() {
+        let half = NonNegative::new(f16::from_f32(0.1));
+        let single: NonNegative<f32> = half.into();
+        let double: NonNegative<f64> = half.into();
+        assert_eq!(single.get(), f16::from_f32(0.1).to_f32());
+        assert_eq!(double.get(), f16::from_f32(0.1).to_f64());
+
+        let raw: f32 = Positive::new(bf16::from_f32(3.0)).into();
+        assert_eq!(raw, 3.0);
+        let raw: f16 = half.into();
+        assert_eq!(raw, f16::from_f32(0.1));
+    }
+
+    #[test]
+    fn test_narrowing() {
+        let ok = NonNegative::<f16>::try_from(NonNegative::new(0.25f32)).unwrap();
+        assert_eq!(ok.get(), f16::from_f32(0.25));
+        assert_eq!(
+            NonNegative::<f16>::try_from(NonNegative::new(1e6f64)).unwrap_err(),
+            NonNegativeError::Infinite(f64::INFINITY)
+        );
+        // Rounds to zero in `f16`, which `Positive` rejects.
+        assert_eq!(
+            Positive::<f16>::try_from(Positive::new(1e-10f32)).unwrap_err(),
+            NonNegativeError::Zero
+        );
+        assert!(NonNegative::<bf16>::try_from(NonNegative::new(1e30f32)).is_ok());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde() {
+        // `half` serializes as the raw bits, which is compact in binary
+        // formats and round-trips in all of them.
+        let value = NonNegative::new(f16::from_f32(1.5));
+        let json = serde_json::to_string(&value).unwrap();
+        assert_eq!(json, f16::from_f32(1.5).to_bits().to_string());
+        assert_eq!(
+            serde_json::from_str::<NonNegative<f16>>(&json).unwrap(),
+            value
+        );
+
+        let bytes = bincode::serialize(&value).unwrap();
+        assert_eq!(bytes.len(), 2);
+        assert_eq!(
+            bincode::deserialize::<NonNegative<f16>>(&bytes).unwrap(),
+            value
+        );
+
+        let negative = f16::from_f32(-1.5).to_bits().to_string();
+        assert!(serde_json::from_str::<NonNegative<f16>>(&negative).is_err());
+    }
+}
@@ -3,7 +3,10 @@
 //! Ensures that values are >= 0 and finite, providing safe construction
 //! methods and a convenient macro.
 //!
-//! Supports any float type implementing `num_traits::Float`.
+//! Supports any float type implementing `num_traits::Float`. The companion
+//! [`Positive`] type additionally rejects zero, and [`UnitInterval`] holds
+//! values in `[0, 1]`. All three are aliases of the generic [`Bounded`]
+//! wrapper, which can be reused for other ranges by implementing [`Bounds`].
 //!
 //! # Examples
 //!
@@ -12,106 +15,509 @@
 //!
 //! let zero = NonNegative::<f64>::zero();
 //! let val = NonNegative::try_new(3.14).unwrap();
-//! let macro_val = nonneg!(5.0f64).unwrap();
+//! let macro_val = nonneg!(5.0);
 //!
 //! assert_eq!(zero.get(), 0.0);
 //! assert_eq!(val.get(), 3.14);
 //! assert_eq!(macro_val.get(), 5.0);
 //! ```
+//!
+//! Literals passed to `nonneg!` are checked at compile time, and `f32`/`f64`
+//! values can be built in constants:
+//!
+//! ```
+//! use nonneg_float::{NonNegative, nonneg};
+//!
+//! const MAX_RATE: NonNegative<f64> = NonNegative::<f64>::new_const(0.25);
+//! const MIN_SCALE: NonNegative<f32> = nonneg!(f32, 0.5);
+//!
+//! assert_eq!(MAX_RATE.get(), 0.25);
+//! assert_eq!(MIN_SCALE.get(), 0.5);
+//! ```
+//!
+//! # `no_std`
+//!
+//! The crate is `no_std` when the default `std` feature is disabled. Float
+//! math then comes from `libm`, so enable the `libm` feature instead. The
+//! `alloc` feature (implied by `std`) provides `NonNegativeVec`.
+//!
+//! ```toml
+//! nonneg-float = { version = "0.1", default-features = false, features = ["libm"] }
+//! ```
+
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+#[cfg(not(any(feature = "std", feature = "libm")))]
+compile_error!("nonneg-float requires either the `std` or the `libm` feature");
+
+use core::fmt;
+use core::iter::{Product, Sum};
+use core::num::ParseFloatError;
+use core::ops::{Add, AddAssign, Bound, Div, Mul, MulAssign, Sub};
 use num_traits::Float;
-use std::fmt;
 
-#[cfg(feature = "serde")]
-use serde::{Deserialize, Serialize};
+#[cfg(feature = "approx")]
+mod approx_impl;
+#[cfg(feature = "arbitrary")]
+mod arbitrary_impl;
+mod bounded;
+#[cfg(feature = "bytemuck")]
+mod bytemuck_impl;
+mod convert;
+#[cfg(feature = "diesel")]
+mod diesel_impl;
+#[cfg(feature = "rand")]
+pub mod distr;
+#[cfg(feature = "half")]
+mod half_impl;
+mod log_space;
+mod math;
+mod num_traits_impl;
+mod positive;
+#[cfg(feature = "rusqlite")]
+mod rusqlite_impl;
+#[cfg(feature = "schemars")]
+mod schemars_impl;
+#[cfg(all(feature = "serde", feature = "half"))]
+pub mod serde_f32;
+#[cfg(feature = "sqlx")]
+mod sqlx_impl;
+#[cfg(feature = "proptest")]
+pub mod strategy;
+mod unit_interval;
+mod validate;
+#[cfg(feature = "alloc")]
+mod vec;
+
+pub use bounded::{Bounded, Bounds};
+pub use log_space::LogNonNegative;
+pub use positive::{Positive, PositiveBounds};
+pub use unit_interval::{UnitInterval, UnitIntervalBounds};
+#[cfg(feature = "alloc")]
+pub use vec::{NonNegativeVec, NonNegativeVecError};
 
 /// Error type returned when trying to create a `NonNegative` from an invalid value.
-#[derive(Debug, Clone, PartialEq, Eq)]
+///
+/// Each variant records why the value was rejected, and where meaningful the
+/// rejected value itself (converted to `f64`). New variants may be added
+/// as new range types are, so matches need a wildcard arm.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[non_exhaustive]
 pub enum NonNegativeError {
-    /// The value was negative or not finite.
-    InvalidValue,
+    /// The value was less than zero.
+    Negative(f64),
+    /// The value was NaN.
+    NaN,
+    /// The value was positive or negative infinity.
+    Infinite(f64),
+    /// The value was zero where a strictly positive value is required.
+    Zero,
+    /// The value was greater than one where a value in `[0, 1]` is required.
+    GreaterThanOne(f64),
+    /// The value was below the lower bound of a [`Bounded`] range.
+    BelowMinimum(f64),
+    /// The value was above the upper bound of a [`Bounded`] range.
+    AboveMaximum(f64),
+}
+
+impl NonNegativeError {
+    /// Returns the rejected value as `f64`.
+    pub fn value(&self) -> f64 {
+        match *self {
+            NonNegativeError::Negative(value)
+            | NonNegativeError::Infinite(value)
+            | NonNegativeError::GreaterThanOne(value)
+            | NonNegativeError::BelowMinimum(value)
+            | NonNegativeError::AboveMaximum(value) => value,
+            NonNegativeError::NaN => f64::NAN,
+            NonNegativeError::Zero => 0.0,
+        }
+    }
 }
 
 impl fmt::Display for NonNegativeError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
-            NonNegativeError::InvalidValue => write!(f, "Value must be non-negative and finite"),
+            NonNegativeError::Negative(value) => {
+                write!(f, "Value must be non-negative, got {value}")
+            }
+            NonNegativeError::NaN => write!(f, "Value must not be NaN"),
+            NonNegativeError::Infinite(value) => write!(f, "Value must be finite, got {value}"),
+            NonNegativeError::Zero => write!(f, "Value must be positive, got 0"),
+            NonNegativeError::GreaterThanOne(value) => {
+                write!(f, "Value must be at most 1, got {value}")
+            }
+            NonNegativeError::BelowMinimum(value) => {
+                write!(f, "Value is below the lower bound, got {value}")
+            }
+            NonNegativeError::AboveMaximum(value) => {
+                write!(f, "Value is above the upper bound, got {value}")
+            }
         }
     }
 }
 
-impl std::error::Error for NonNegativeError {}
+impl core::error::Error for NonNegativeError {}
+
+/// Error type returned when validating a slice of floats, identifying the
+/// first invalid element.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SliceError {
+    /// Index of the invalid element.
+    pub index: usize,
+    /// Why the element was rejected.
+    pub error: NonNegativeError,
+}
+
+impl fmt::Display for SliceError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Invalid element at index {}: {}", self.index, self.error)
+    }
+}
+
+impl core::error::Error for SliceError {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        Some(&self.error)
+    }
+}
+
+/// Error type returned when parsing a `NonNegative` (or other [`Bounded`]
+/// value) from a string.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ParseNonNegativeError {
+    /// The string was not a valid float.
+    Float(ParseFloatError),
+    /// The string was a valid float, but outside the accepted range.
+    Invalid(NonNegativeError),
+}
+
+impl fmt::Display for ParseNonNegativeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseNonNegativeError::Float(err) => write!(f, "Invalid float: {err}"),
+            ParseNonNegativeError::Invalid(err) => err.fmt(f),
+        }
+    }
+}
+
+impl core::error::Error for ParseNonNegativeError {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            ParseNonNegativeError::Float(err) => Some(err),
+            ParseNonNegativeError::Invalid(err) => Some(err),
+        }
+    }
+}
+
+impl From<ParseFloatError> for ParseNonNegativeError {
+    fn from(err: ParseFloatError) -> Self {
+        ParseNonNegativeError::Float(err)
+    }
+}
+
+impl From<NonNegativeError> for ParseNonNegativeError {
+    fn from(err: NonNegativeError) -> Self {
+        ParseNonNegativeError::Invalid(err)
+    }
+}
+
+/// [`Bounds`] accepting values `>= 0`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct NonNegativeBounds;
+
+impl Bounds for NonNegativeBounds {
+    const LOWER: Bound<f64> = Bound::Included(0.0);
+    const UPPER: Bound<f64> = Bound::Unbounded;
+    const NAME: &'static str = "NonNegative";
+    const MESSAGE: &'static str = "Value must be non-negative and finite";
+    const EXPECTING: &'static str = "a non-negative, finite float";
+
+    fn below(value: f64) -> NonNegativeError {
+        NonNegativeError::Negative(value)
+    }
+}
 
 /// Wrapper type guaranteeing a non-negative floating-point value.
-#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
-#[derive(Copy, Clone, Debug, PartialEq, PartialOrd)]
-pub struct NonNegative<T: Float>(T);
+///
+/// See [`Bounded`] for the construction methods and the traits shared with
+/// the other range types.
+pub type NonNegative<T> = Bounded<T, NonNegativeBounds>;
 
 impl<T: Float> NonNegative<T> {
     /// Returns a `NonNegative` wrapping zero.
-    pub fn zero() -> Self
-    where
-        T: num_traits::Zero,
-    {
-        Self(T::zero())
+    pub fn zero() -> Self {
+        Self::new_unchecked(T::zero())
     }
 
-    /// Attempts to create a new `NonNegative<T>` from a value.
+    /// Creates a `NonNegative<T>`, mapping negative values (including
+    /// negative infinity) to zero.
     ///
-    /// Returns `Err` if the value is negative or not finite.
-    pub fn try_new(value: T) -> Result<Self, NonNegativeError> {
-        if value >= T::zero() && value.is_finite() {
-            Ok(Self(value))
+    /// Returns `Err` if the value is NaN or positive infinity.
+    pub fn clamp_from(value: T) -> Result<Self, NonNegativeError> {
+        if value < T::zero() {
+            Ok(Self::zero())
         } else {
-            Err(NonNegativeError::InvalidValue)
+            Self::try_new(value)
         }
     }
 
-    /// Creates a new `NonNegative<T>` or panics if invalid.
-    ///
-    /// # Panics
-    ///
-    /// Panics if the value is negative or not finite.
-    pub fn new(value: T) -> Self {
-        Self::try_new(value).expect("Value must be non-negative and finite")
+    /// Checked addition. Returns `None` if the sum overflows.
+    pub fn checked_add(self, rhs: Self) -> Option<Self> {
+        Self::try_new(self.get() + rhs.get()).ok()
     }
 
-    /// Returns the inner float value.
-    pub fn get(&self) -> T {
-        self.0
+    /// Checked subtraction. Returns `None` if the difference is negative.
+    pub fn checked_sub(self, rhs: Self) -> Option<Self> {
+        Self::try_new(self.get() - rhs.get()).ok()
+    }
+
+    /// Checked multiplication. Returns `None` if the product overflows.
+    pub fn checked_mul(self, rhs: Self) -> Option<Self> {
+        Self::try_new(self.get() * rhs.get()).ok()
+    }
+
+    /// Checked division. Returns `None` if `rhs` is zero or the quotient
+    /// overflows.
+    pub fn checked_div(self, rhs: Self) -> Option<Self> {
+        Self::try_new(self.get() / rhs.get()).ok()
+    }
+
+    /// Saturating addition. Caps the sum at `T::max_value()`.
+    pub fn saturating_add(self, rhs: Self) -> Self {
+        self.checked_add(rhs)
+            .unwrap_or_else(|| Self::new_unchecked(T::max_value()))
+    }
+
+    /// Saturating subtraction. Floors the difference at zero.
+    pub fn saturating_sub(self, rhs: Self) -> Self {
+        self.checked_sub(rhs).unwrap_or_else(Self::zero)
+    }
+
+    /// Sums values using Neumaier's compensated summation, which keeps the
+    /// rounding error independent of the number of terms.
+    ///
+    /// Returns `Err` if the sum overflows to infinity.
+    pub fn kahan_sum<I>(iter: I) -> Result<Self, NonNegativeError>
+    where
+        I: IntoIterator<Item = Self>,
+    {
+        let mut sum = T::zero();
+        let mut compensation = T::zero();
+        for value in iter {
+            let value = value.get();
+            let total = sum + value;
+            if total.is_infinite() {
+                return Err(NonNegativeError::Infinite(f64::INFINITY));
+            }
+            if sum >= value {
+                compensation = compensation + ((sum - total) + value);
+            } else {
+                compensation = compensation + ((value - total) + sum);
+            }
+            sum = total;
+        }
+        Self::try_new(sum + compensation)
     }
 }
 
-impl<T: Float + num_traits::Zero> Default for NonNegative<T> {
+impl<T: Float> Default for NonNegative<T> {
     fn default() -> Self {
         Self::zero()
     }
 }
 
-impl<T: Float + fmt::Display> fmt::Display for NonNegative<T> {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        self.0.fmt(f)
+/// Adds two non-negative values.
+///
+/// # Panics
+///
+/// Panics if the sum overflows to infinity.
+impl<T: Float> Add for NonNegative<T> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        self.checked_add(rhs).expect("attempt to add with overflow")
     }
 }
 
-/// Macro to create a `NonNegative` value.
+/// Multiplies two non-negative values.
+///
+/// # Panics
+///
+/// Panics if the product overflows to infinity.
+impl<T: Float> Mul for NonNegative<T> {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self {
+        self.checked_mul(rhs)
+            .expect("attempt to multiply with overflow")
+    }
+}
+
+impl<T: Float> AddAssign for NonNegative<T> {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl<T: Float> MulAssign for NonNegative<T> {
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
+
+/// Subtracts two non-negative values, returning the raw difference.
+///
+/// The result may be negative, so it is not wrapped.
+impl<T: Float> Sub for NonNegative<T> {
+    type Output = T;
+
+    fn sub(self, rhs: Self) -> T {
+        self.get() - rhs.get()
+    }
+}
+
+/// Divides two non-negative values.
+///
+/// Returns `Err` when dividing by zero or when the quotient overflows.
+impl<T: Float> Div for NonNegative<T> {
+    type Output = Result<Self, NonNegativeError>;
+
+    fn div(self, rhs: Self) -> Self::Output {
+        Self::try_new(self.get() / rhs.get())
+    }
+}
+
+impl<T: Float> Add<T> for NonNegative<T> {
+    type Output = Result<Self, NonNegativeError>;
+
+    fn add(self, rhs: T) -> Self::Output {
+        Self::try_new(self.get() + rhs)
+    }
+}
+
+impl<T: Float> Sub<T> for NonNegative<T> {
+    type Output = T;
+
+    fn sub(self, rhs: T) -> T {
+        self.get() - rhs
+    }
+}
+
+impl<T: Float> Mul<T> for NonNegative<T> {
+    type Output = Result<Self, NonNegativeError>;
+
+    fn mul(self, rhs: T) -> Self::Output {
+        Self::try_new(self.get() * rhs)
+    }
+}
+
+impl<T: Float> Div<T> for NonNegative<T> {
+    type Output = Result<Self, NonNegativeError>;
+
+    fn div(self, rhs: T) -> Self::Output {
+        Self::try_new(self.get() / rhs)
+    }
+}
+
+/// Sums non-negative values.
+///
+/// # Panics
+///
+/// Panics if the sum overflows to infinity. Collect into a
+/// `Result<NonNegative<T>, NonNegativeError>` to handle overflow instead.
+impl<T: Float> Sum for NonNegative<T> {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::zero(), Add::add)
+    }
+}
+
+impl<'a, T: Float> Sum<&'a NonNegative<T>> for NonNegative<T> {
+    fn sum<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+        iter.copied().sum()
+    }
+}
+
+/// Sums non-negative values, returning `Err` if the sum overflows.
+impl<T: Float> Sum<NonNegative<T>> for Result<NonNegative<T>, NonNegativeError> {
+    fn sum<I: Iterator<Item = NonNegative<T>>>(mut iter: I) -> Self {
+        iter.try_fold(NonNegative::zero(), |acc, value| acc + value.get())
+    }
+}
+
+/// Multiplies non-negative values.
+///
+/// # Panics
 ///
-/// Returns `Result<NonNegative<T>, NonNegativeError>`.
+/// Panics if the product overflows to infinity. Collect into a
+/// `Result<NonNegative<T>, NonNegativeError>` to handle overflow instead.
+impl<T: Float> Product for NonNegative<T> {
+    fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::new_unchecked(T::one()), Mul::mul)
+    }
+}
+
+impl<'a, T: Float> Product<&'a NonNegative<T>> for NonNegative<T> {
+    fn product<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+        iter.copied().product()
+    }
+}
+
+/// Multiplies non-negative values, returning `Err` if the product overflows.
+impl<T: Float> Product<NonNegative<T>> for Result<NonNegative<T>, NonNegativeError> {
+    fn product<I: Iterator<Item = NonNegative<T>>>(mut iter: I) -> Self {
+        iter.try_fold(NonNegative::new_unchecked(T::one()), |acc, value| {
+            acc * value.get()
+        })
+    }
+}
+
+/// Macro to create a `NonNegative` value.
 ///
 /// Usage:
-/// - `nonneg!(value)` infers type and creates a `NonNegative` from `value`.
 /// - `nonneg!(Type)` creates a default zero value of that type.
-/// - `nonneg!(Type, value)` creates a `NonNegative` of the specified type.
+/// - `nonneg!(literal)` creates a `NonNegative`, checked at compile time. The
+///   float type follows the literal's suffix (`2.5f32`), or else the
+///   context, defaulting to `f64`.
+/// - `nonneg!(Type, literal)` creates a `NonNegative<Type>` (`f32` or `f64`,
+///   or `f16`/`bf16` with the `half` feature), checked at compile time.
+/// - `nonneg!(value)` infers type and returns
+///   `Result<NonNegative<T>, NonNegativeError>`. A bare identifier is parsed
+///   as a type, so wrap variables in the two-argument form or an expression.
+/// - `nonneg!(Type, value)` returns `Result<NonNegative<Type>, NonNegativeError>`.
+///
+/// A negative literal fails to compile:
+///
+/// ```compile_fail
+/// let x = nonneg_float::nonneg!(-1.0);
+/// ```
 #[macro_export]
 macro_rules! nonneg {
     ($t:ty) => {
         $crate::NonNegative::<$t>::zero()
     };
-    ($val:expr) => {{ $crate::NonNegative::try_new($val) }};
-    ($t:ty, $val:expr) => {{ $crate::NonNegative::<$t>::try_new($val) }};
+    (- $val:literal) => {
+        $crate::bounded!(@literal $crate::NonNegativeBounds, - $val)
+    };
+    (- $($rest:tt)+) => {
+        $crate::bounded!($crate::NonNegative<_>, - $($rest)+)
+    };
+    ($val:literal) => {
+        $crate::bounded!(@literal $crate::NonNegativeBounds, $val)
+    };
+    ($val:expr) => {
+        $crate::bounded!($crate::NonNegative<_>, $val)
+    };
+    ($t:ty, $($val:tt)+) => {
+        $crate::bounded!($crate::NonNegative<$t>, $($val)+)
+    };
 }
 
 #[cfg(test)]
+// The baseline tests use values like `3.14`, which are not meant as `PI`.
+#[allow(clippy::approx_constant)]
 mod tests {
     use super::*;
 
@@ -133,18 +539,138 @@ mod tests {
     fn test_try_new_invalid() {
         assert_eq!(
             NonNegative::try_new(-0.1f64).unwrap_err(),
-            NonNegativeError::InvalidValue
+            NonNegativeError::Negative(-0.1)
         );
         assert_eq!(
             NonNegative::try_new(f64::NAN).unwrap_err(),
-            NonNegativeError::InvalidValue
+            NonNegativeError::NaN
         );
         assert_eq!(
             NonNegative::try_new(f64::INFINITY).unwrap_err(),
-            NonNegativeError::InvalidValue
+            NonNegativeError::Infinite(f64::INFINITY)
+        );
+        assert_eq!(
+            NonNegative::try_new(f64::NEG_INFINITY).unwrap_err(),
+            NonNegativeError::Infinite(f64::NEG_INFINITY)
+        );
+        assert_eq!(
+            NonNegative::try_new(-1.5f32).unwrap_err(),
+            NonNegativeError::Negative(-1.5)
         );
     }
 
+    #[test]
+    fn test_negative_zero_normalised() {
+        let zero = NonNegative::new(-0.0f64);
+        assert!(zero.get().is_sign_positive());
+        assert_eq!(zero, NonNegative::zero());
+    }
+
+    #[test]
+    fn test_ord() {
+        let mut values: Vec<_> = [3.0f64, 0.5, 2.0, 0.0]
+            .into_iter()
+            .map(NonNegative::new)
+            .collect();
+        values.sort();
+        let sorted: Vec<f64> = values.iter().map(NonNegative::get).collect();
+        assert_eq!(sorted, [0.0, 0.5, 2.0, 3.0]);
+
+        let a = NonNegative::new(1.0f64);
+        let b = NonNegative::new(2.0f64);
+        let c = NonNegative::new(3.0f64);
+        assert_eq!(a.max(b), b);
+        assert_eq!(a.min(b), a);
+        assert_eq!(c.clamp(a, b), b);
+    }
+
+    #[test]
+    fn test_hash_and_maps() {
+        use std::collections::{BTreeMap, HashMap};
+
+        let mut btree = BTreeMap::new();
+        btree.insert(NonNegative::new(2.0f64), "two");
+        btree.insert(NonNegative::new(1.0f64), "one");
+        assert_eq!(btree.values().copied().collect::<Vec<_>>(), ["one", "two"]);
+
+        let mut map = HashMap::new();
+        map.insert(NonNegative::new(0.0f64), "zero");
+        assert_eq!(map.get(&NonNegative::new(-0.0)), Some(&"zero"));
+    }
+
+    #[test]
+    fn test_from_str() {
+        let value: NonNegative<f64> = "1e-3".parse().unwrap();
+        assert_eq!(value.get(), 1e-3);
+        assert_eq!(" 2.5\n".parse::<NonNegative<f32>>().unwrap().get(), 2.5);
+
+        assert_eq!(
+            "-1".parse::<NonNegative<f64>>().unwrap_err(),
+            ParseNonNegativeError::Invalid(NonNegativeError::Negative(-1.0))
+        );
+        let inf = "inf".parse::<NonNegative<f64>>().unwrap_err();
+        assert_eq!(
+            inf,
+            ParseNonNegativeError::Invalid(NonNegativeError::Infinite(f64::INFINITY))
+        );
+        assert_eq!(inf.to_string(), "Value must be finite, got inf");
+        assert!(matches!(
+            "NaN".parse::<NonNegative<f64>>(),
+            Err(ParseNonNegativeError::Invalid(NonNegativeError::NaN))
+        ));
+
+        let syntax = "1.0.0".parse::<NonNegative<f64>>().unwrap_err();
+        assert!(matches!(syntax, ParseNonNegativeError::Float(_)));
+        assert_eq!(syntax.to_string(), "Invalid float: invalid float literal");
+        assert!(matches!(
+            "".parse::<NonNegative<f64>>(),
+            Err(ParseNonNegativeError::Float(_))
+        ));
+    }
+
+    #[test]
+    fn test_slice_views() {
+        let values = [NonNegative::new(1.0f32), NonNegative::new(2.5)];
+        assert_eq!(NonNegative::as_slice_of_floats(&values), [1.0, 2.5]);
+
+        let raw = [0.0f64, 1.5, 3.0];
+        let view = NonNegative::try_from_slice(&raw).unwrap();
+        assert_eq!(view, [0.0, 1.5, 3.0].map(NonNegative::new));
+        assert_eq!(view.as_ptr().cast::<f64>(), raw.as_ptr());
+
+        let err = NonNegative::try_from_slice(&[1.0f64, 2.0, -3.0, f64::NAN]).unwrap_err();
+        assert_eq!(
+            err,
+            SliceError {
+                index: 2,
+                error: NonNegativeError::Negative(-3.0)
+            }
+        );
+        assert_eq!(
+            err.to_string(),
+            "Invalid element at index 2: Value must be non-negative, got -3"
+        );
+        assert_eq!(
+            NonNegative::try_from_slice(&[-0.0f32]).unwrap_err().index,
+            0
+        );
+    }
+
+    #[test]
+    fn test_error_display() {
+        assert_eq!(
+            NonNegativeError::Negative(-2.5).to_string(),
+            "Value must be non-negative, got -2.5"
+        );
+        assert_eq!(NonNegativeError::NaN.to_string(), "Value must not be NaN");
+        assert_eq!(
+            NonNegativeError::Infinite(f64::INFINITY).to_string(),
+            "Value must be finite, got inf"
+        );
+        assert!(NonNegativeError::NaN.value().is_nan());
+        assert_eq!(NonNegativeError::Negative(-2.5).value(), -2.5);
+    }
+
     #[test]
     fn test_new_panics() {
         let _ = NonNegative::new(1.0f64); // okay
@@ -158,16 +684,188 @@ mod tests {
 
     #[test]
     fn test_macro() {
-        let a = nonneg!(5.0f64).unwrap();
+        let a = nonneg!(5.0f64);
         assert_eq!(a.get(), 5.0);
 
         let b = nonneg!(f64);
         assert_eq!(b.get(), 0.0);
 
-        let c = nonneg!(f32, 2.71).unwrap();
+        let c = nonneg!(f32, 2.71);
         assert_eq!(c.get(), 2.71);
 
-        let d = nonneg!(-1.0f64);
+        let x = 1.0f64;
+        let d = nonneg!(-x);
         assert!(d.is_err());
+
+        let y = 0.5;
+        let e = nonneg!(f32, y).unwrap();
+        assert_eq!(e.get(), 0.5);
+        assert!(nonneg!(f32, -y).is_err());
+        assert_eq!(nonneg!(2.0 * x).unwrap().get(), 2.0);
+    }
+
+    #[test]
+    fn test_macro_literal_type() {
+        let a: NonNegative<f32> = nonneg!(2.5f32);
+        assert_eq!(a.get(), 2.5f32);
+        let b = nonneg!(-0.0f32);
+        assert!(b.get().is_sign_positive());
+        let c: NonNegative<f32> = nonneg!(0.5);
+        assert_eq!(c.get(), 0.5f32);
+        const D: NonNegative<f32> = nonneg!(1.5f32);
+        assert_eq!(D.get(), 1.5);
+    }
+
+    #[test]
+    fn test_new_const() {
+        const RATE: NonNegative<f64> = NonNegative::<f64>::new_const(0.25);
+        const ZERO: NonNegative<f32> = NonNegative::<f32>::new_const(-0.0);
+        const LIT: NonNegative<f64> = nonneg!(3.0);
+        assert_eq!(RATE.get(), 0.25);
+        assert!(ZERO.get().is_sign_positive());
+        assert_eq!(LIT.get(), 3.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "Value must be non-negative and finite")]
+    fn test_new_const_panics_at_runtime() {
+        let value = std::hint::black_box(-1.0f64);
+        let _ = NonNegative::<f64>::new_const(value);
+    }
+
+    #[test]
+    fn test_add_mul() {
+        let a = NonNegative::new(1.5f64);
+        let b = NonNegative::new(2.0f64);
+        assert_eq!((a + b).get(), 3.5);
+        assert_eq!((a * b).get(), 3.0);
+
+        let mut c = a;
+        c += b;
+        assert_eq!(c.get(), 3.5);
+        c *= b;
+        assert_eq!(c.get(), 7.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to add with overflow")]
+    fn test_add_overflow_panics() {
+        let max = NonNegative::new(f64::MAX);
+        let _ = max + max;
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to multiply with overflow")]
+    fn test_mul_overflow_panics() {
+        let max = NonNegative::new(f64::MAX);
+        let _ = max * NonNegative::new(2.0);
+    }
+
+    #[test]
+    fn test_sub_div() {
+        let a = NonNegative::new(1.0f64);
+        let b = NonNegative::new(4.0f64);
+        assert_eq!(a - b, -3.0);
+        assert_eq!((a / b).unwrap().get(), 0.25);
+        assert!((a / NonNegative::zero()).is_err());
+        assert!((NonNegative::<f64>::zero() / NonNegative::zero()).is_err());
+    }
+
+    #[test]
+    fn test_checked_ops() {
+        let a = NonNegative::new(1.0f64);
+        let b = NonNegative::new(4.0f64);
+        let max = NonNegative::new(f64::MAX);
+        assert_eq!(a.checked_add(b).unwrap().get(), 5.0);
+        assert!(max.checked_add(max).is_none());
+        assert_eq!(b.checked_sub(a).unwrap().get(), 3.0);
+        assert!(a.checked_sub(b).is_none());
+        assert_eq!(a.checked_mul(b).unwrap().get(), 4.0);
+        assert!(max.checked_mul(b).is_none());
+        assert_eq!(a.checked_div(b).unwrap().get(), 0.25);
+        assert!(a.checked_div(NonNegative::zero()).is_none());
+    }
+
+    #[test]
+    fn test_saturating_ops() {
+        let a = NonNegative::new(1.0f64);
+        let b = NonNegative::new(4.0f64);
+        let max = NonNegative::new(f64::MAX);
+        assert_eq!(a.saturating_add(b).get(), 5.0);
+        assert_eq!(max.saturating_add(max).get(), f64::MAX);
+        assert_eq!(b.saturating_sub(a).get(), 3.0);
+        assert_eq!(a.saturating_sub(b).get(), 0.0);
+    }
+
+    #[test]
+    fn test_clamp_from() {
+        assert_eq!(NonNegative::clamp_from(2.5f64).unwrap().get(), 2.5);
+        assert_eq!(NonNegative::clamp_from(-2.5f64).unwrap().get(), 0.0);
+        assert_eq!(
+            NonNegative::clamp_from(f64::NEG_INFINITY).unwrap().get(),
+            0.0
+        );
+        assert!(NonNegative::clamp_from(f64::NAN).is_err());
+        assert!(NonNegative::clamp_from(f64::INFINITY).is_err());
+    }
+
+    #[test]
+    fn test_sum_product() {
+        let values: Vec<_> = [1.0f64, 2.0, 4.0].map(NonNegative::new).to_vec();
+        let sum: NonNegative<f64> = values.iter().sum();
+        assert_eq!(sum.get(), 7.0);
+        let product: NonNegative<f64> = values.into_iter().product();
+        assert_eq!(product.get(), 8.0);
+
+        let empty: [NonNegative<f64>; 0] = [];
+        assert_eq!(empty.iter().sum::<NonNegative<f64>>().get(), 0.0);
+        assert_eq!(empty.iter().product::<NonNegative<f64>>().get(), 1.0);
+    }
+
+    #[test]
+    fn test_sum_product_overflow() {
+        let max = NonNegative::new(f64::MAX);
+        let sum: Result<NonNegative<f64>, _> = [max, max].into_iter().sum();
+        assert_eq!(sum, Err(NonNegativeError::Infinite(f64::INFINITY)));
+        let product: Result<NonNegative<f64>, _> = [max, max].into_iter().product();
+        assert!(product.is_err());
+        let ok: Result<NonNegative<f64>, _> = [max].into_iter().sum();
+        assert_eq!(ok, Ok(max));
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to add with overflow")]
+    fn test_sum_overflow_panics() {
+        let max = NonNegative::new(f64::MAX);
+        let _: NonNegative<f64> = [max, max].into_iter().sum();
+    }
+
+    #[test]
+    fn test_kahan_sum() {
+        let mut values = vec![NonNegative::new(1.0f64)];
+        values.extend(std::iter::repeat_n(NonNegative::new(1e-16), 10_000));
+
+        let naive: NonNegative<f64> = values.iter().sum();
+        assert_eq!(naive.get(), 1.0);
+        let compensated = NonNegative::kahan_sum(values).unwrap();
+        assert_eq!(compensated.get(), 1.0 + 1e-12);
+
+        let max = NonNegative::new(f64::MAX);
+        assert_eq!(
+            NonNegative::kahan_sum([max, max]),
+            Err(NonNegativeError::Infinite(f64::INFINITY))
+        );
+    }
+
+    #[test]
+    fn test_mixed_ops() {
+        let a = NonNegative::new(2.0f64);
+        assert_eq!((a + 1.0).unwrap().get(), 3.0);
+        assert!((a + -3.0).is_err());
+        assert_eq!(a - 3.0, -1.0);
+        assert_eq!((a * 0.5).unwrap().get(), 1.0);
+        assert!((a * -1.0).is_err());
+        assert_eq!((a / 4.0).unwrap().get(), 0.5);
+        assert!((a / 0.0).is_err());
     }
 }
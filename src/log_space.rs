@@ -0,0 +1,266 @@
+//! A non-negative magnitude stored as its natural logarithm.
+
+use crate::{NonNegative, NonNegativeError};
+use core::cmp::Ordering;
+use core::iter::{Product, Sum};
+use core::ops::{Add, AddAssign, Mul, MulAssign};
+use num_traits::Float;
+
+/// A non-negative value `x` stored as `ln(x)`, with negative infinity
+/// representing zero.
+///
+/// Products of many small values, such as likelihoods, stay representable
+/// long after they would underflow to zero as a [`NonNegative`].
+/// Multiplication adds logarithms, and addition uses a numerically stable
+/// log-sum-exp, so results stay non-negative by construction. Ordering
+/// follows the represented values.
+///
+/// # Examples
+///
+/// ```
+/// use nonneg_float::{LogNonNegative, NonNegative};
+///
+/// let p = LogNonNegative::from(NonNegative::new(1e-5f64));
+/// let likelihood: LogNonNegative<f64> = std::iter::repeat(p).take(1000).product();
+/// assert!((likelihood.ln() - 1000.0 * 1e-5f64.ln()).abs() < 1e-6);
+///
+/// // As a plain float the product underflows to zero.
+/// assert_eq!(NonNegative::try_from(likelihood).unwrap().get(), 0.0);
+///
+/// let half = LogNonNegative::from(NonNegative::new(0.5f64));
+/// assert_eq!(NonNegative::try_from(half + half).unwrap().get(), 1.0);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LogNonNegative<T: Float>(T);
+
+impl<T: Float> LogNonNegative<T> {
+    /// Creates a `LogNonNegative` from a logarithm.
+    ///
+    /// Returns `Err` if `ln` is NaN or positive infinity.
+    pub fn from_ln(ln: T) -> Result<Self, NonNegativeError> {
+        if ln.is_nan() {
+            Err(NonNegativeError::NaN)
+        } else if ln == T::infinity() {
+            Err(NonNegativeError::Infinite(f64::INFINITY))
+        } else {
+            Ok(Self(ln))
+        }
+    }
+
+    /// Returns a `LogNonNegative` representing zero.
+    pub fn zero() -> Self {
+        Self(T::neg_infinity())
+    }
+
+    /// Returns a `LogNonNegative` representing one.
+    pub fn one() -> Self {
+        Self(T::zero())
+    }
+
+    /// Returns `ln(x)`, which is negative infinity for zero.
+    pub fn ln(self) -> T {
+        self.0
+    }
+
+    /// Checked multiplication. Returns `None` if the logarithm of the
+    /// product overflows.
+    pub fn checked_mul(self, rhs: Self) -> Option<Self> {
+        Self::from_ln(self.0 + rhs.0).ok()
+    }
+}
+
+impl<T: Float> Default for LogNonNegative<T> {
+    fn default() -> Self {
+        Self::zero()
+    }
+}
+
+impl<T: Float> From<NonNegative<T>> for LogNonNegative<T> {
+    fn from(value: NonNegative<T>) -> Self {
+        Self(value.get().ln())
+    }
+}
+
+/// Converts back to a plain value. Tiny magnitudes underflow to zero.
+///
+/// Returns `Err` if the value is too large to represent as `T`.
+impl<T: Float> TryFrom<LogNonNegative<T>> for NonNegative<T> {
+    type Error = NonNegativeError;
+
+    fn try_from(value: LogNonNegative<T>) -> Result<Self, Self::Error> {
+        NonNegative::try_new(value.0.exp())
+    }
+}
+
+impl<T: Float> Eq for LogNonNegative<T> {}
+
+impl<T: Float> PartialOrd for LogNonNegative<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T: Float> Ord for LogNonNegative<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // The invariant excludes NaN, so `partial_cmp` always succeeds.
+        self.0.partial_cmp(&other.0).unwrap_or(Ordering::Equal)
+    }
+}
+
+/// Adds the represented values with log-sum-exp. Never overflows.
+impl<T: Float> Add for LogNonNegative<T> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        let (hi, lo) = if self.0 >= rhs.0 {
+            (self.0, rhs.0)
+        } else {
+            (rhs.0, self.0)
+        };
+        if lo == T::neg_infinity() {
+            return Self(hi);
+        }
+        Self(hi + (lo - hi).exp().ln_1p())
+    }
+}
+
+/// Multiplies the represented values by adding logarithms.
+///
+/// # Panics
+///
+/// Panics if the logarithm of the product overflows.
+impl<T: Float> Mul for LogNonNegative<T> {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self {
+        self.checked_mul(rhs)
+            .expect("attempt to multiply with overflow")
+    }
+}
+
+impl<T: Float> AddAssign for LogNonNegative<T> {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl<T: Float> MulAssign for LogNonNegative<T> {
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
+
+impl<T: Float> Sum for LogNonNegative<T> {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::zero(), Add::add)
+    }
+}
+
+/// Multiplies the represented values.
+///
+/// # Panics
+///
+/// Panics if the logarithm of the product overflows.
+impl<T: Float> Product for LogNonNegative<T> {
+    fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::one(), Mul::mul)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn log(value: f64) -> LogNonNegative<f64> {
+        LogNonNegative::from(NonNegative::new(value))
+    }
+
+    #[test]
+    fn test_from_ln() {
+        assert_eq!(LogNonNegative::from_ln(-3.0f64).unwrap().ln(), -3.0);
+        assert_eq!(
+            LogNonNegative::from_ln(f64::NEG_INFINITY).unwrap(),
+            LogNonNegative::zero()
+        );
+        assert_eq!(
+            LogNonNegative::from_ln(f64::NAN).unwrap_err(),
+            NonNegativeError::NaN
+        );
+        assert_eq!(
+            LogNonNegative::from_ln(f32::INFINITY).unwrap_err(),
+            NonNegativeError::Infinite(f64::INFINITY)
+        );
+    }
+
+    #[test]
+    fn test_conversions() {
+        assert_eq!(log(0.0), LogNonNegative::zero());
+        assert_eq!(log(1.0), LogNonNegative::one());
+        assert_eq!(NonNegative::try_from(log(4.0)).unwrap().get(), 4.0);
+        assert_eq!(
+            NonNegative::try_from(LogNonNegative::<f64>::zero())
+                .unwrap()
+                .get(),
+            0.0
+        );
+        let huge = LogNonNegative::from_ln(1000.0f64).unwrap();
+        assert_eq!(
+            NonNegative::try_from(huge).unwrap_err(),
+            NonNegativeError::Infinite(f64::INFINITY)
+        );
+    }
+
+    #[test]
+    fn test_mul() {
+        assert!((NonNegative::try_from(log(2.0) * log(3.0)).unwrap().get() - 6.0).abs() < 1e-12);
+        assert_eq!(log(5.0) * LogNonNegative::zero(), LogNonNegative::zero());
+
+        let tiny = log(1e-300);
+        let mut product = tiny;
+        product *= tiny;
+        assert!((product.ln() - 2.0 * 1e-300f64.ln()).abs() < 1e-9);
+
+        let max = LogNonNegative::from_ln(f64::MAX).unwrap();
+        assert!(max.checked_mul(max).is_none());
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to multiply with overflow")]
+    fn test_mul_overflow_panics() {
+        let max = LogNonNegative::from_ln(f64::MAX).unwrap();
+        let _ = max * max;
+    }
+
+    #[test]
+    fn test_add() {
+        assert!((NonNegative::try_from(log(2.0) + log(3.0)).unwrap().get() - 5.0).abs() < 1e-12);
+        assert_eq!(log(7.0) + LogNonNegative::zero(), log(7.0));
+        assert_eq!(
+            LogNonNegative::<f64>::zero() + LogNonNegative::zero(),
+            LogNonNegative::zero()
+        );
+
+        // Stable for magnitudes far outside the range of `f64`.
+        let a = LogNonNegative::from_ln(-5000.0f64).unwrap();
+        let mut sum = a;
+        sum += a;
+        assert!((sum.ln() - (-5000.0 + 2f64.ln())).abs() < 1e-9);
+
+        let max = LogNonNegative::from_ln(f64::MAX).unwrap();
+        assert_eq!(max + max, max);
+    }
+
+    #[test]
+    fn test_sum_product_and_ordering() {
+        let values = [log(1.0), log(2.0), log(3.0)];
+        let sum: LogNonNegative<f64> = values.iter().copied().sum();
+        assert!((sum.ln() - 6f64.ln()).abs() < 1e-12);
+        let product: LogNonNegative<f64> = values.iter().copied().product();
+        assert!((product.ln() - 6f64.ln()).abs() < 1e-12);
+
+        let mut sorted = [log(3.0), LogNonNegative::zero(), log(0.5)];
+        sorted.sort();
+        assert_eq!(sorted, [LogNonNegative::zero(), log(0.5), log(3.0)]);
+        assert!(log(1e-300) > LogNonNegative::zero());
+    }
+}
@@ -0,0 +1,158 @@
+//! Math functions with non-negative results.
+//!
+//! Functions that cannot overflow return `NonNegative<T>` directly; those
+//! that can return `Err(NonNegativeError::Infinite(inf))` when the result
+//! reaches infinity.
+
+use crate::{NonNegative, NonNegativeError};
+use num_traits::Float;
+
+impl<T: Float> NonNegative<T> {
+    /// Creates a `NonNegative<T>` from the absolute value of `value`.
+    ///
+    /// Returns `Err` if `value` is NaN or infinite.
+    pub fn abs_of(value: T) -> Result<Self, NonNegativeError> {
+        Self::try_new(value.abs())
+    }
+
+    /// Creates a `NonNegative<T>` from `e^value`.
+    ///
+    /// Negative infinity gives zero. Returns `Err` if `value` is NaN or the
+    /// result overflows to infinity, e.g. above about `709.78` for `f64`.
+    pub fn exp_of(value: T) -> Result<Self, NonNegativeError> {
+        Self::try_new(value.exp())
+    }
+
+    /// Returns the square root. Never overflows.
+    pub fn sqrt(self) -> Self {
+        Self::new_unchecked(self.get().sqrt())
+    }
+
+    /// Returns the cube root. Never overflows.
+    pub fn cbrt(self) -> Self {
+        Self::new_unchecked(self.get().cbrt())
+    }
+
+    /// Raises to a non-negative power. `0^0` is `1`.
+    ///
+    /// Returns `Err` if the result overflows to infinity.
+    pub fn powf(self, exponent: Self) -> Result<Self, NonNegativeError> {
+        Self::try_new(self.get().powf(exponent.get()))
+    }
+
+    /// Raises to an integer power.
+    ///
+    /// Returns `Err` if the result overflows to infinity, including zero
+    /// raised to a negative power.
+    pub fn powi(self, exponent: i32) -> Result<Self, NonNegativeError> {
+        Self::try_new(self.get().powi(exponent))
+    }
+
+    /// Returns `sqrt(self² + other²)` without intermediate overflow.
+    ///
+    /// Returns `Err` if the result itself overflows to infinity.
+    pub fn hypot(self, other: Self) -> Result<Self, NonNegativeError> {
+        Self::try_new(self.get().hypot(other.get()))
+    }
+
+    /// Returns the absolute difference `|self - other|`. Never overflows.
+    pub fn abs_diff(self, other: Self) -> Self {
+        Self::new_unchecked((self.get() - other.get()).abs())
+    }
+
+    /// Returns the natural logarithm, which may be negative.
+    ///
+    /// The logarithm of zero is negative infinity.
+    pub fn ln(self) -> T {
+        self.get().ln()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_abs_of_and_exp_of() {
+        assert_eq!(NonNegative::abs_of(-2.5f64).unwrap().get(), 2.5);
+        assert!(
+            NonNegative::abs_of(-0.0f64)
+                .unwrap()
+                .get()
+                .is_sign_positive()
+        );
+        assert_eq!(
+            NonNegative::abs_of(f64::NEG_INFINITY).unwrap_err(),
+            NonNegativeError::Infinite(f64::INFINITY)
+        );
+        assert_eq!(
+            NonNegative::abs_of(f32::NAN).unwrap_err(),
+            NonNegativeError::NaN
+        );
+
+        assert_eq!(NonNegative::exp_of(0.0f64).unwrap().get(), 1.0);
+        assert_eq!(NonNegative::exp_of(f64::NEG_INFINITY).unwrap().get(), 0.0);
+        assert_eq!(
+            NonNegative::exp_of(710.0f64).unwrap_err(),
+            NonNegativeError::Infinite(f64::INFINITY)
+        );
+        assert_eq!(
+            NonNegative::exp_of(f64::NAN).unwrap_err(),
+            NonNegativeError::NaN
+        );
+    }
+
+    #[test]
+    fn test_roots() {
+        assert_eq!(NonNegative::new(16.0f64).sqrt().get(), 4.0);
+        assert_eq!(NonNegative::new(27.0f32).cbrt().get(), 3.0);
+        assert_eq!(NonNegative::new(f64::MAX).sqrt().get(), f64::MAX.sqrt());
+        assert_eq!(NonNegative::<f64>::zero().sqrt().get(), 0.0);
+    }
+
+    #[test]
+    fn test_powers() {
+        let two = NonNegative::new(2.0f64);
+        assert_eq!(two.powf(NonNegative::new(0.5)).unwrap().get(), 2f64.sqrt());
+        assert_eq!(
+            NonNegative::<f64>::zero()
+                .powf(NonNegative::zero())
+                .unwrap()
+                .get(),
+            1.0
+        );
+        assert_eq!(
+            two.powf(NonNegative::new(1024.0)).unwrap_err(),
+            NonNegativeError::Infinite(f64::INFINITY)
+        );
+
+        assert_eq!(two.powi(-2).unwrap().get(), 0.25);
+        assert!(NonNegative::<f64>::zero().powi(-1).is_err());
+        assert!(two.powi(1024).is_err());
+    }
+
+    #[test]
+    fn test_abs_diff() {
+        let a = NonNegative::new(1.5f64);
+        let b = NonNegative::new(4.0f64);
+        assert_eq!(a.abs_diff(b).get(), 2.5);
+        assert_eq!(b.abs_diff(a).get(), 2.5);
+        assert!(a.abs_diff(a).get().is_sign_positive());
+        let max = NonNegative::new(f64::MAX);
+        assert_eq!(max.abs_diff(NonNegative::zero()), max);
+    }
+
+    #[test]
+    fn test_hypot_and_ln() {
+        let three = NonNegative::new(3.0f64);
+        assert_eq!(three.hypot(NonNegative::new(4.0)).unwrap().get(), 5.0);
+        let big = NonNegative::new(1e300f64);
+        assert!(big.hypot(big).unwrap().get().is_finite());
+        let max = NonNegative::new(f64::MAX);
+        assert!(max.hypot(max).is_err());
+
+        assert_eq!(NonNegative::new(1.0f64).ln(), 0.0);
+        assert!(NonNegative::new(0.5f64).ln() < 0.0);
+        assert_eq!(NonNegative::<f64>::zero().ln(), f64::NEG_INFINITY);
+    }
+}
@@ -0,0 +1,165 @@
+//! `num-traits` impls, so `NonNegative<T>` works in generic numeric code.
+//!
+//! `CheckedSub` and `CheckedDiv` are not implemented: they require `Sub` and
+//! `Div` to output `Self`, whereas subtraction returns the raw `T` (the
+//! difference may be negative) and division returns a `Result`. Use the
+//! inherent [`NonNegative::checked_sub`] and [`NonNegative::checked_div`]
+//! instead.
+
+use crate::NonNegative;
+use num_traits::{CheckedAdd, CheckedMul, Float, FromPrimitive, NumCast, One, ToPrimitive, Zero};
+
+impl<T: Float> Zero for NonNegative<T> {
+    fn zero() -> Self {
+        Self::new_unchecked(T::zero())
+    }
+
+    fn is_zero(&self) -> bool {
+        self.get().is_zero()
+    }
+}
+
+impl<T: Float> One for NonNegative<T> {
+    fn one() -> Self {
+        Self::new_unchecked(T::one())
+    }
+}
+
+/// Bounded by zero and `T::max_value()`.
+impl<T: Float> num_traits::Bounded for NonNegative<T> {
+    fn min_value() -> Self {
+        Self::new_unchecked(T::zero())
+    }
+
+    fn max_value() -> Self {
+        Self::new_unchecked(T::max_value())
+    }
+}
+
+impl<T: Float> ToPrimitive for NonNegative<T> {
+    fn to_i64(&self) -> Option<i64> {
+        self.get().to_i64()
+    }
+
+    fn to_u64(&self) -> Option<u64> {
+        self.get().to_u64()
+    }
+
+    fn to_i128(&self) -> Option<i128> {
+        self.get().to_i128()
+    }
+
+    fn to_u128(&self) -> Option<u128> {
+        self.get().to_u128()
+    }
+
+    fn to_f32(&self) -> Option<f32> {
+        self.get().to_f32()
+    }
+
+    fn to_f64(&self) -> Option<f64> {
+        self.get().to_f64()
+    }
+}
+
+/// Returns `None` if the value is not representable as `T` or is negative,
+/// NaN or infinite.
+impl<T: Float> NumCast for NonNegative<T> {
+    fn from<N: ToPrimitive>(n: N) -> Option<Self> {
+        Self::try_new(T::from(n)?).ok()
+    }
+}
+
+/// Returns `None` for negative, NaN or infinite inputs.
+impl<T: Float> FromPrimitive for NonNegative<T> {
+    fn from_i64(n: i64) -> Option<Self> {
+        <Self as NumCast>::from(n)
+    }
+
+    fn from_u64(n: u64) -> Option<Self> {
+        <Self as NumCast>::from(n)
+    }
+
+    fn from_f32(n: f32) -> Option<Self> {
+        <Self as NumCast>::from(n)
+    }
+
+    fn from_f64(n: f64) -> Option<Self> {
+        <Self as NumCast>::from(n)
+    }
+}
+
+impl<T: Float> CheckedAdd for NonNegative<T> {
+    fn checked_add(&self, rhs: &Self) -> Option<Self> {
+        NonNegative::checked_add(*self, *rhs)
+    }
+}
+
+impl<T: Float> CheckedMul for NonNegative<T> {
+    fn checked_mul(&self, rhs: &Self) -> Option<Self> {
+        NonNegative::checked_mul(*self, *rhs)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn checked_total<N: Zero + CheckedAdd + Copy>(values: &[N]) -> Option<N> {
+        values
+            .iter()
+            .try_fold(N::zero(), |acc, value| acc.checked_add(value))
+    }
+
+    #[test]
+    fn test_identities_and_bounds() {
+        assert!(<NonNegative<f64> as Zero>::zero().is_zero());
+        assert!(!NonNegative::new(0.5f64).is_zero());
+        assert_eq!(<NonNegative<f32> as One>::one().get(), 1.0);
+        assert_eq!(
+            <NonNegative<f64> as num_traits::Bounded>::min_value().get(),
+            0.0
+        );
+        assert_eq!(
+            <NonNegative<f64> as num_traits::Bounded>::max_value().get(),
+            f64::MAX
+        );
+    }
+
+    #[test]
+    fn test_generic_checked_ops() {
+        let values = [1.0f64, 2.5, 4.0].map(NonNegative::new);
+        assert_eq!(checked_total(&values).unwrap().get(), 7.5);
+        let max = NonNegative::new(f64::MAX);
+        assert_eq!(checked_total(&[max, max]), None);
+        assert_eq!(CheckedMul::checked_mul(&max, &max), None);
+        assert_eq!(
+            CheckedMul::checked_mul(&values[1], &values[2])
+                .unwrap()
+                .get(),
+            10.0
+        );
+    }
+
+    #[test]
+    fn test_casts() {
+        let n = NonNegative::new(3.75f64);
+        assert_eq!(n.to_u8(), Some(3));
+        assert_eq!(n.to_i64(), Some(3));
+        assert_eq!(n.to_f32(), Some(3.75));
+        assert_eq!(NonNegative::new(1e20f64).to_u32(), None);
+
+        let cast: NonNegative<f32> = NumCast::from(42u64).unwrap();
+        assert_eq!(cast.get(), 42.0);
+        assert_eq!(<NonNegative<f64> as NumCast>::from(-1i32), None);
+        assert_eq!(<NonNegative<f64> as NumCast>::from(f64::NAN), None);
+
+        assert_eq!(NonNegative::<f64>::from_i64(7).unwrap().get(), 7.0);
+        assert_eq!(NonNegative::<f64>::from_i64(-7), None);
+        assert_eq!(NonNegative::<f32>::from_f64(1e300), None);
+        assert_eq!(
+            NonNegative::<f32>::from_u64(u64::MAX).unwrap().get(),
+            u64::MAX as f32
+        );
+    }
+}
@@ -0,0 +1,186 @@
+//! A companion wrapper for strictly positive floating point values.
+
+use crate::{Bounded, Bounds, NonNegative, NonNegativeError};
+use core::ops::{Bound, Div};
+use num_traits::Float;
+
+/// [`Bounds`] accepting values `> 0`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct PositiveBounds;
+
+impl Bounds for PositiveBounds {
+    const LOWER: Bound<f64> = Bound::Excluded(0.0);
+    const UPPER: Bound<f64> = Bound::Unbounded;
+    const NAME: &'static str = "Positive";
+    const MESSAGE: &'static str = "Value must be positive and finite";
+    const EXPECTING: &'static str = "a positive, finite float";
+
+    fn below(value: f64) -> NonNegativeError {
+        if value == 0.0 {
+            NonNegativeError::Zero
+        } else {
+            NonNegativeError::Negative(value)
+        }
+    }
+}
+
+/// Wrapper type guaranteeing a strictly positive floating-point value.
+///
+/// Unlike [`NonNegative`], zero is rejected, which makes `Positive<T>` safe
+/// to use as a divisor or scale factor. See [`Bounded`] for the construction
+/// methods.
+pub type Positive<T> = Bounded<T, PositiveBounds>;
+
+impl<T: Float> From<Positive<T>> for NonNegative<T> {
+    fn from(value: Positive<T>) -> Self {
+        NonNegative::new_unchecked(value.get())
+    }
+}
+
+impl<T: Float> TryFrom<NonNegative<T>> for Positive<T> {
+    type Error = NonNegativeError;
+
+    fn try_from(value: NonNegative<T>) -> Result<Self, Self::Error> {
+        Self::try_new(value.get())
+    }
+}
+
+/// Divides a non-negative value by a positive one.
+///
+/// # Panics
+///
+/// Panics if the quotient overflows to infinity.
+impl<T: Float> Div<Positive<T>> for NonNegative<T> {
+    type Output = NonNegative<T>;
+
+    fn div(self, rhs: Positive<T>) -> NonNegative<T> {
+        NonNegative::try_new(self.get() / rhs.get()).expect("attempt to divide with overflow")
+    }
+}
+
+/// Macro to create a `Positive` value.
+///
+/// Follows the same forms as [`nonneg!`](crate::nonneg), except that there
+/// is no zero default:
+/// - `positive!(literal)` creates a `Positive`, checked at compile time, with
+///   the float type inferred as for `nonneg!`.
+/// - `positive!(Type, literal)` creates a `Positive<Type>` (`f32` or `f64`,
+///   or `f16`/`bf16` with the `half` feature), checked at compile time.
+/// - `positive!(value)` and `positive!(Type, value)` return
+///   `Result<Positive<T>, NonNegativeError>`.
+///
+/// ```compile_fail
+/// let x = nonneg_float::positive!(0.0);
+/// ```
+///
+/// Literals are checked in their inferred type, so one that rounds to zero
+/// as `f32` fails too:
+///
+/// ```compile_fail
+/// let x: nonneg_float::Positive<f32> = nonneg_float::positive!(1e-50);
+/// ```
+#[macro_export]
+macro_rules! positive {
+    (- $val:literal) => {
+        $crate::bounded!(@literal $crate::PositiveBounds, - $val)
+    };
+    (- $($rest:tt)+) => {
+        $crate::bounded!($crate::Positive<_>, - $($rest)+)
+    };
+    ($val:literal) => {
+        $crate::bounded!(@literal $crate::PositiveBounds, $val)
+    };
+    ($val:expr) => {
+        $crate::bounded!($crate::Positive<_>, $val)
+    };
+    ($t:ty, $($val:tt)+) => {
+        $crate::bounded!($crate::Positive<$t>, $($val)+)
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_try_new() {
+        assert_eq!(Positive::try_new(2.5f64).unwrap().get(), 2.5);
+        assert_eq!(
+            Positive::try_new(0.0f64).unwrap_err(),
+            NonNegativeError::Zero
+        );
+        assert_eq!(
+            Positive::try_new(-0.0f64).unwrap_err(),
+            NonNegativeError::Zero
+        );
+        assert_eq!(
+            Positive::try_new(-1.0f64).unwrap_err(),
+            NonNegativeError::Negative(-1.0)
+        );
+        assert_eq!(
+            Positive::try_new(f32::NAN).unwrap_err(),
+            NonNegativeError::NaN
+        );
+        assert_eq!(
+            Positive::try_new(f64::INFINITY).unwrap_err(),
+            NonNegativeError::Infinite(f64::INFINITY)
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Value must be positive and finite")]
+    fn test_new_panics_on_zero() {
+        let _ = Positive::new(0.0f64);
+    }
+
+    #[test]
+    fn test_macro() {
+        const SCALE: Positive<f64> = positive!(2.0);
+        assert_eq!(SCALE.get(), 2.0);
+        assert_eq!(positive!(f32, 0.5).get(), 0.5);
+        let small: Positive<f32> = positive!(0.25f32);
+        assert_eq!(small.get(), 0.25);
+        // Checked in the inferred type: fine as `f64`, though it would
+        // round to zero as `f32`.
+        let tiny: Positive<f64> = positive!(1e-50);
+        assert_eq!(tiny.get(), 1e-50);
+
+        let x = 1.0f64;
+        assert!(positive!(-x).is_err());
+        assert!(positive!(x - 1.0).is_err());
+        assert_eq!(positive!(f32, 3.0 * 2.0).unwrap().get(), 6.0);
+    }
+
+    #[test]
+    fn test_conversions() {
+        let p = Positive::new(3.0f64);
+        let n: NonNegative<f64> = p.into();
+        assert_eq!(n.get(), 3.0);
+        assert_eq!(Positive::try_from(n).unwrap(), p);
+        assert_eq!(
+            Positive::try_from(NonNegative::<f64>::zero()).unwrap_err(),
+            NonNegativeError::Zero
+        );
+    }
+
+    #[test]
+    fn test_div() {
+        let n = NonNegative::new(3.0f64);
+        assert_eq!((n / Positive::new(2.0)).get(), 1.5);
+        assert_eq!((NonNegative::zero() / Positive::new(2.0f64)).get(), 0.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to divide with overflow")]
+    fn test_div_overflow_panics() {
+        let _ = NonNegative::new(f64::MAX) / Positive::new(0.5);
+    }
+
+    #[test]
+    fn test_ord() {
+        let a = Positive::new(1.0f64);
+        let b = Positive::new(2.0f64);
+        assert!(a < b);
+        assert_eq!(a.max(b), b);
+    }
+}
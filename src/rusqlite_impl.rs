@@ -0,0 +1,81 @@
+//! `rusqlite` support: values are stored as `REAL` and validated on read.
+
+use crate::{Bounded, Bounds};
+use rusqlite::types::{FromSql, FromSqlError, FromSqlResult, ToSql, ToSqlOutput, ValueRef};
+
+macro_rules! impl_rusqlite {
+    ($($t:ty),*) => {$(
+        impl<B: Bounds> ToSql for Bounded<$t, B> {
+            fn to_sql(&self) -> rusqlite::Result<ToSqlOutput<'_>> {
+                Ok(ToSqlOutput::from(f64::from(self.get())))
+            }
+        }
+
+        /// Reads a `REAL` (or `INTEGER`) column, returning
+        /// [`FromSqlError::Other`] wrapping a
+        /// [`NonNegativeError`](crate::NonNegativeError) if it is out of
+        /// bounds.
+        impl<B: Bounds> FromSql for Bounded<$t, B> {
+            fn column_result(value: ValueRef<'_>) -> FromSqlResult<Self> {
+                let raw = <$t>::column_result(value)?;
+                Self::try_new(raw).map_err(|err| FromSqlError::Other(Box::new(err)))
+            }
+        }
+    )*};
+}
+
+impl_rusqlite!(f32, f64);
+
+#[cfg(test)]
+mod tests {
+    use crate::{NonNegative, NonNegativeError, Positive};
+    use rusqlite::types::FromSqlError;
+    use rusqlite::{Connection, Error};
+
+    fn connection() -> Connection {
+        let conn = Connection::open_in_memory().unwrap();
+        conn.execute_batch("CREATE TABLE amounts (id INTEGER PRIMARY KEY, value REAL);")
+            .unwrap();
+        conn
+    }
+
+    fn read<T: rusqlite::types::FromSql>(conn: &Connection, id: i64) -> rusqlite::Result<T> {
+        conn.query_row("SELECT value FROM amounts WHERE id = ?1", [id], |row| {
+            row.get(0)
+        })
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let conn = connection();
+        let amount = NonNegative::new(12.5f64);
+        conn.execute(
+            "INSERT INTO amounts (id, value) VALUES (1, ?1), (2, ?2)",
+            (amount, NonNegative::new(0.25f32)),
+        )
+        .unwrap();
+        assert_eq!(read::<NonNegative<f64>>(&conn, 1).unwrap(), amount);
+        assert_eq!(read::<NonNegative<f32>>(&conn, 2).unwrap().get(), 0.25);
+    }
+
+    #[test]
+    fn test_invalid_row_is_conversion_error() {
+        let conn = connection();
+        conn.execute_batch("INSERT INTO amounts (id, value) VALUES (1, -3.0), (2, 0.0);")
+            .unwrap();
+
+        let err = read::<NonNegative<f64>>(&conn, 1).unwrap_err();
+        let Error::FromSqlConversionFailure(_, _, source) = err else {
+            panic!("unexpected error {err:?}");
+        };
+        assert_eq!(
+            source.downcast_ref::<NonNegativeError>(),
+            Some(&NonNegativeError::Negative(-3.0))
+        );
+
+        let err = read::<Positive<f32>>(&conn, 2).unwrap_err();
+        assert!(matches!(err, Error::FromSqlConversionFailure(..)));
+        assert!(!matches!(err, Error::FromSqlConversionFailure(_, _, ref e)
+            if e.downcast_ref::<FromSqlError>().is_some()));
+    }
+}
@@ -0,0 +1,114 @@
+//! `schemars` support: JSON Schemas carrying the range from [`Bounds`].
+
+use crate::{Bounded, Bounds};
+use alloc::borrow::Cow;
+use alloc::format;
+use core::ops::Bound;
+use num_traits::Float;
+use schemars::{JsonSchema, Schema, SchemaGenerator};
+use serde_json::Value;
+
+/// Converts a bound to JSON, writing integral values as integers so that
+/// `0.0` appears as `0`.
+fn to_json(value: f64) -> Value {
+    if value.fract() == 0.0 && value.abs() < 2f64.powi(53) {
+        Value::from(value as i64)
+    } else {
+        Value::from(value)
+    }
+}
+
+/// Emits the schema of `T` (a `number`) with `minimum`/`exclusiveMinimum`
+/// and `maximum`/`exclusiveMaximum` taken from `B`, and a description noting
+/// that NaN and infinities are rejected.
+impl<T: Float + JsonSchema, B: Bounds> JsonSchema for Bounded<T, B> {
+    fn inline_schema() -> bool {
+        true
+    }
+
+    fn schema_name() -> Cow<'static, str> {
+        Cow::Borrowed(B::NAME)
+    }
+
+    fn json_schema(generator: &mut SchemaGenerator) -> Schema {
+        let mut schema = T::json_schema(generator);
+        match B::LOWER {
+            Bound::Included(min) => schema.insert("minimum".into(), to_json(min)),
+            Bound::Excluded(min) => schema.insert("exclusiveMinimum".into(), to_json(min)),
+            Bound::Unbounded => None,
+        };
+        match B::UPPER {
+            Bound::Included(max) => schema.insert("maximum".into(), to_json(max)),
+            Bound::Excluded(max) => schema.insert("exclusiveMaximum".into(), to_json(max)),
+            Bound::Unbounded => None,
+        };
+        schema.insert(
+            "description".into(),
+            Value::from(format!(
+                "Must be {}. NaN and infinities are rejected.",
+                B::EXPECTING
+            )),
+        );
+        schema
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Bounded, Bounds, NonNegative, Positive, UnitInterval};
+    use core::ops::Bound;
+    use schemars::{JsonSchema, schema_for};
+    use serde_json::json;
+
+    #[test]
+    fn test_non_negative_schema() {
+        assert_eq!(
+            schema_for!(NonNegative<f64>).as_value(),
+            &json!({
+                "$schema": "https://json-schema.org/draft/2020-12/schema",
+                "title": "NonNegative",
+                "type": "number",
+                "format": "double",
+                "minimum": 0,
+                "description": "Must be a non-negative, finite float. NaN and infinities are rejected."
+            })
+        );
+    }
+
+    #[test]
+    fn test_companion_schemas() {
+        let positive = schema_for!(Positive<f32>);
+        assert_eq!(positive.get("exclusiveMinimum"), Some(&json!(0)));
+        assert_eq!(positive.get("format"), Some(&json!("float")));
+        assert_eq!(positive.get("minimum"), None);
+
+        let unit = schema_for!(UnitInterval<f64>);
+        assert_eq!(unit.get("minimum"), Some(&json!(0)));
+        assert_eq!(unit.get("maximum"), Some(&json!(1)));
+    }
+
+    #[test]
+    fn test_custom_bounds_and_fields() {
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+        struct HalfOpen;
+
+        impl Bounds for HalfOpen {
+            const LOWER: Bound<f64> = Bound::Included(-0.5);
+            const UPPER: Bound<f64> = Bound::Excluded(2.5);
+        }
+
+        #[derive(JsonSchema)]
+        #[allow(dead_code)]
+        struct Config {
+            rate: NonNegative<f64>,
+            offset: Bounded<f64, HalfOpen>,
+        }
+
+        let schema = schema_for!(Config);
+        let properties = &schema.as_value()["properties"];
+        assert_eq!(properties["rate"]["minimum"], json!(0));
+        assert_eq!(properties["offset"]["minimum"], json!(-0.5));
+        assert_eq!(properties["offset"]["exclusiveMaximum"], json!(2.5));
+        assert!(schema.get("$defs").is_none());
+    }
+}
@@ -0,0 +1,136 @@
+//! Serializes a bounded value as an `f32` number, for use with
+//! `#[serde(with = "nonneg_float::serde_f32")]`.
+//!
+//! Meant for half-precision values: `f16` and `bf16` serialize as their raw
+//! bits by default, which is compact but unreadable in formats like JSON.
+//! Every half-precision value is exactly representable as `f32`.
+//! Deserialization validates the number as given, then rounds it to `T`
+//! and validates again, so values that overflow `T` are rejected as
+//! infinite and tiny negatives are not rounded into range.
+//!
+//! # Examples
+//!
+//! ```
+//! use half::f16;
+//! use nonneg_float::NonNegative;
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct Layer {
+//!     #[serde(with = "nonneg_float::serde_f32")]
+//!     scale: NonNegative<f16>,
+//! }
+//!
+//! let layer: Layer = serde_json::from_str(r#"{"scale":0.5}"#).unwrap();
+//! assert_eq!(serde_json::to_string(&layer).unwrap(), r#"{"scale":0.5}"#);
+//! assert!(serde_json::from_str::<Layer>(r#"{"scale":-0.5}"#).is_err());
+//! ```
+
+use crate::{Bounded, Bounds};
+use half::{bf16, f16};
+use num_traits::Float;
+use serde::de::Unexpected;
+use serde::{Deserialize, Deserializer, Serializer};
+
+mod sealed {
+    pub trait Sealed {}
+
+    impl Sealed for half::f16 {}
+    impl Sealed for half::bf16 {}
+}
+
+/// Half-precision float types these helpers accept, `f16` and `bf16`.
+///
+/// Wider types are excluded, as serializing them as `f32` would silently
+/// lose precision.
+pub trait HalfFloat: Float + sealed::Sealed {
+    #[doc(hidden)]
+    fn widen(self) -> f32;
+
+    #[doc(hidden)]
+    fn narrow(value: f64) -> Self;
+}
+
+macro_rules! impl_half_float {
+    ($($t:ident),*) => {$(
+        impl HalfFloat for $t {
+            fn widen(self) -> f32 {
+                self.to_f32()
+            }
+
+            fn narrow(value: f64) -> Self {
+                $t::from_f64(value)
+            }
+        }
+    )*};
+}
+
+impl_half_float!(f16, bf16);
+
+/// Serializes the value as an `f32`, which is exact.
+pub fn serialize<T, B, S>(value: &Bounded<T, B>, serializer: S) -> Result<S::Ok, S::Error>
+where
+    T: HalfFloat,
+    B: Bounds,
+    S: Serializer,
+{
+    serializer.serialize_f32(value.get().widen())
+}
+
+/// Deserializes a number, validates it, then rounds it to `T` and
+/// validates it again.
+pub fn deserialize<'de, T, B, D>(deserializer: D) -> Result<Bounded<T, B>, D::Error>
+where
+    T: HalfFloat,
+    B: Bounds,
+    D: Deserializer<'de>,
+{
+    let raw = f64::deserialize(deserializer)?;
+    Bounded::<f64, B>::try_new(raw)
+        .and_then(|_| Bounded::try_new(T::narrow(raw)))
+        .map_err(|err| {
+            serde::de::Error::invalid_value(Unexpected::Float(err.value()), &B::EXPECTING)
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{NonNegative, UnitInterval};
+    use serde::Serialize;
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Weights {
+        #[serde(with = "crate::serde_f32")]
+        scale: NonNegative<f16>,
+        #[serde(with = "crate::serde_f32")]
+        dropout: UnitInterval<bf16>,
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let weights = Weights {
+            scale: NonNegative::new(f16::from_f32(1.5)),
+            dropout: UnitInterval::new(bf16::from_f32(0.25)),
+        };
+        let json = serde_json::to_string(&weights).unwrap();
+        assert_eq!(json, r#"{"scale":1.5,"dropout":0.25}"#);
+        assert_eq!(serde_json::from_str::<Weights>(&json).unwrap(), weights);
+    }
+
+    #[test]
+    fn test_rejects_invalid() {
+        let overflow = r#"{"scale":1e6,"dropout":0.5}"#;
+        assert!(serde_json::from_str::<Weights>(overflow).is_err());
+        let out_of_range = r#"{"scale":1.0,"dropout":1.5}"#;
+        assert!(serde_json::from_str::<Weights>(out_of_range).is_err());
+        // Rounds to `-0.0` as `f16`, but is negative as given.
+        let tiny_negative = r#"{"scale":-1e-10,"dropout":0.5}"#;
+        assert!(serde_json::from_str::<Weights>(tiny_negative).is_err());
+
+        // Values round to the nearest representable half-precision value.
+        let rounded: Weights = serde_json::from_str(r#"{"scale":0.1,"dropout":1e-30}"#).unwrap();
+        assert_eq!(rounded.scale.get(), f16::from_f64(0.1));
+        assert_eq!(rounded.dropout.get(), bf16::from_f64(1e-30));
+    }
+}
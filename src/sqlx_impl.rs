@@ -0,0 +1,117 @@
+//! `sqlx` support for any database that can store `f32` / `f64`.
+//!
+//! Values use the SQL type of the wrapped float and are validated on
+//! decode, so an out-of-bounds row yields a decode error.
+
+use crate::{Bounded, Bounds};
+use sqlx::encode::IsNull;
+use sqlx::error::BoxDynError;
+use sqlx::{Database, Decode, Encode, Type};
+
+macro_rules! impl_sqlx {
+    ($($t:ty),*) => {$(
+        impl<DB: Database, B: Bounds> Type<DB> for Bounded<$t, B>
+        where
+            $t: Type<DB>,
+        {
+            fn type_info() -> DB::TypeInfo {
+                <$t as Type<DB>>::type_info()
+            }
+
+            fn compatible(ty: &DB::TypeInfo) -> bool {
+                <$t as Type<DB>>::compatible(ty)
+            }
+        }
+
+        impl<'q, DB: Database, B: Bounds> Encode<'q, DB> for Bounded<$t, B>
+        where
+            $t: Encode<'q, DB>,
+        {
+            fn encode_by_ref(
+                &self,
+                buf: &mut <DB as Database>::ArgumentBuffer<'q>,
+            ) -> Result<IsNull, BoxDynError> {
+                self.get().encode_by_ref(buf)
+            }
+
+            fn size_hint(&self) -> usize {
+                self.get().size_hint()
+            }
+        }
+
+        /// Returns the [`NonNegativeError`](crate::NonNegativeError) as the
+        /// decode error if the value is out of bounds.
+        impl<'r, DB: Database, B: Bounds> Decode<'r, DB> for Bounded<$t, B>
+        where
+            $t: Decode<'r, DB>,
+        {
+            fn decode(value: <DB as Database>::ValueRef<'r>) -> Result<Self, BoxDynError> {
+                Ok(Self::try_new(<$t>::decode(value)?)?)
+            }
+        }
+    )*};
+}
+
+impl_sqlx!(f32, f64);
+
+#[cfg(test)]
+mod tests {
+    use crate::{NonNegative, NonNegativeError, UnitInterval};
+    use sqlx::sqlite::SqlitePool;
+    use sqlx::{Error, Row};
+
+    async fn pool() -> SqlitePool {
+        let pool = SqlitePool::connect("sqlite::memory:").await.unwrap();
+        sqlx::query("CREATE TABLE amounts (id INTEGER PRIMARY KEY, value REAL)")
+            .execute(&pool)
+            .await
+            .unwrap();
+        pool
+    }
+
+    #[tokio::test]
+    async fn test_round_trip() {
+        let pool = pool().await;
+        sqlx::query("INSERT INTO amounts (id, value) VALUES (1, ?), (2, ?)")
+            .bind(NonNegative::new(7.5f64))
+            .bind(UnitInterval::new(0.5f32))
+            .execute(&pool)
+            .await
+            .unwrap();
+
+        let (value,): (NonNegative<f64>,) =
+            sqlx::query_as("SELECT value FROM amounts WHERE id = 1")
+                .fetch_one(&pool)
+                .await
+                .unwrap();
+        assert_eq!(value.get(), 7.5);
+
+        let row = sqlx::query("SELECT value FROM amounts WHERE id = 2")
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+        assert_eq!(row.get::<UnitInterval<f32>, _>(0).get(), 0.5);
+    }
+
+    #[tokio::test]
+    async fn test_invalid_row_is_decode_error() {
+        let pool = pool().await;
+        sqlx::query("INSERT INTO amounts (id, value) VALUES (1, -2.0)")
+            .execute(&pool)
+            .await
+            .unwrap();
+
+        let row = sqlx::query("SELECT value FROM amounts WHERE id = 1")
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+        let err = row.try_get::<NonNegative<f64>, _>(0).unwrap_err();
+        let Error::ColumnDecode { source, .. } = err else {
+            panic!("unexpected error {err:?}");
+        };
+        assert_eq!(
+            source.downcast_ref::<NonNegativeError>(),
+            Some(&NonNegativeError::Negative(-2.0))
+        );
+    }
+}
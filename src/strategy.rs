@@ -0,0 +1,126 @@
+//! `proptest` strategies for generating valid values.
+//!
+//! # Examples
+//!
+//! ```
+//! use nonneg_float::strategy::{any_nonneg, nonneg_in};
+//! use proptest::prelude::*;
+//! use proptest::test_runner::TestRunner;
+//!
+//! let mut runner = TestRunner::default();
+//! let terms = (any_nonneg::<f64>(), nonneg_in(0.0..=1.0));
+//! runner
+//!     .run(&terms, |(a, b)| {
+//!         if let Some(sum) = a.checked_add(b) {
+//!             prop_assert!(sum >= a && sum >= b);
+//!         }
+//!         Ok(())
+//!     })
+//!     .unwrap();
+//! ```
+
+use crate::NonNegative;
+use core::fmt::Debug;
+use core::ops::RangeInclusive;
+use num_traits::Float;
+use proptest::num;
+use proptest::prelude::*;
+
+mod sealed {
+    pub trait Sealed {}
+
+    impl Sealed for f32 {}
+    impl Sealed for f64 {}
+}
+
+/// Float types that strategies in this module can generate, `f32` and `f64`.
+pub trait StrategyFloat: Float + Debug + sealed::Sealed + 'static {
+    #[doc(hidden)]
+    fn finite_non_negative() -> BoxedStrategy<Self>;
+
+    #[doc(hidden)]
+    fn in_range(range: RangeInclusive<Self>) -> BoxedStrategy<Self>;
+}
+
+macro_rules! impl_strategy_float {
+    ($($t:ident),*) => {$(
+        impl StrategyFloat for $t {
+            fn finite_non_negative() -> BoxedStrategy<Self> {
+                (num::$t::POSITIVE | num::$t::ZERO | num::$t::SUBNORMAL | num::$t::NORMAL).boxed()
+            }
+
+            fn in_range(range: RangeInclusive<Self>) -> BoxedStrategy<Self> {
+                range.boxed()
+            }
+        }
+    )*};
+}
+
+impl_strategy_float!(f32, f64);
+
+/// Generates any non-negative finite value.
+///
+/// Besides values spread over the whole range, edge cases are generated
+/// often: zero, the smallest positive subnormal and normal values, `MAX`,
+/// and values in `[MAX / 2, MAX]` whose sums overflow.
+pub fn any_nonneg<T: StrategyFloat>() -> impl Strategy<Value = NonNegative<T>> {
+    let max = T::max_value();
+    let two = T::one() + T::one();
+    prop_oneof![
+        6 => T::finite_non_negative(),
+        1 => Just(T::zero()),
+        1 => Just(T::min_positive_value() * T::epsilon()),
+        1 => Just(T::min_positive_value()),
+        1 => Just(max),
+        2 => T::in_range(max / two..=max),
+    ]
+    .prop_map(NonNegative::new)
+}
+
+/// Generates non-negative values in `range`.
+///
+/// # Panics
+///
+/// Panics if either end of `range` is negative or not finite.
+pub fn nonneg_in<T: StrategyFloat>(
+    range: RangeInclusive<T>,
+) -> impl Strategy<Value = NonNegative<T>> {
+    let (start, end) = range.into_inner();
+    let (start, end) = (NonNegative::new(start).get(), NonNegative::new(end).get());
+    T::in_range(start..=end).prop_map(NonNegative::new)
+}
+
+/// Generates non-negative values in `[0, max]`.
+///
+/// # Panics
+///
+/// Panics if `max` is negative or not finite.
+pub fn nonneg_up_to<T: StrategyFloat>(max: T) -> impl Strategy<Value = NonNegative<T>> {
+    nonneg_in(T::zero()..=max)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::strategy::ValueTree;
+    use proptest::test_runner::TestRunner;
+
+    #[test]
+    fn test_any_nonneg_hits_edge_cases() {
+        let mut runner = TestRunner::deterministic();
+        let strategy = any_nonneg::<f64>();
+        let values: Vec<f64> = (0..500)
+            .map(|_| strategy.new_tree(&mut runner).unwrap().current().get())
+            .collect();
+        assert!(values.contains(&0.0));
+        assert!(values.contains(&f64::MAX));
+        assert!(values.iter().any(|v| v.is_subnormal()));
+        assert!(values.iter().all(|v| v.is_finite() && v.is_sign_positive()));
+    }
+
+    #[test]
+    #[should_panic(expected = "Value must be non-negative and finite")]
+    fn test_nonneg_in_rejects_negative_start() {
+        let _ = nonneg_in(-1.0f64..=1.0);
+    }
+}
@@ -0,0 +1,162 @@
+//! A wrapper for floating point values in the closed interval `[0, 1]`.
+
+use crate::{Bounded, Bounds, NonNegative, NonNegativeError};
+use core::ops::{Bound, Mul, MulAssign};
+use num_traits::Float;
+
+/// [`Bounds`] accepting values in `[0, 1]`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct UnitIntervalBounds;
+
+impl Bounds for UnitIntervalBounds {
+    const LOWER: Bound<f64> = Bound::Included(0.0);
+    const UPPER: Bound<f64> = Bound::Included(1.0);
+    const NAME: &'static str = "UnitInterval";
+    const MESSAGE: &'static str = "Value must be between 0 and 1";
+    const EXPECTING: &'static str = "a float between 0 and 1";
+
+    fn below(value: f64) -> NonNegativeError {
+        NonNegativeError::Negative(value)
+    }
+
+    fn above(value: f64) -> NonNegativeError {
+        NonNegativeError::GreaterThanOne(value)
+    }
+}
+
+/// Wrapper type guaranteeing a floating-point value in `[0, 1]`.
+///
+/// Useful for probabilities, ratios and blend factors. Multiplication is
+/// closed over the interval, and every value converts losslessly into a
+/// [`NonNegative`]. See [`Bounded`] for the construction methods.
+pub type UnitInterval<T> = Bounded<T, UnitIntervalBounds>;
+
+impl<T: Float> UnitInterval<T> {
+    /// Returns a `UnitInterval` wrapping zero.
+    pub fn zero() -> Self {
+        Self::new_unchecked(T::zero())
+    }
+
+    /// Returns a `UnitInterval` wrapping one.
+    pub fn one() -> Self {
+        Self::new_unchecked(T::one())
+    }
+
+    /// Returns the complement `1 - p`.
+    pub fn complement(self) -> Self {
+        Self::new_unchecked(T::one() - self.get())
+    }
+}
+
+impl<T: Float> Default for UnitInterval<T> {
+    fn default() -> Self {
+        Self::zero()
+    }
+}
+
+impl<T: Float> Mul for UnitInterval<T> {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self {
+        Self::new_unchecked(self.get() * rhs.get())
+    }
+}
+
+impl<T: Float> MulAssign for UnitInterval<T> {
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
+
+/// Scales a non-negative value by a factor in `[0, 1]`, which cannot
+/// overflow.
+impl<T: Float> Mul<UnitInterval<T>> for NonNegative<T> {
+    type Output = NonNegative<T>;
+
+    fn mul(self, rhs: UnitInterval<T>) -> NonNegative<T> {
+        NonNegative::new_unchecked(self.get() * rhs.get())
+    }
+}
+
+impl<T: Float> From<UnitInterval<T>> for NonNegative<T> {
+    fn from(value: UnitInterval<T>) -> Self {
+        NonNegative::new_unchecked(value.get())
+    }
+}
+
+impl<T: Float> TryFrom<NonNegative<T>> for UnitInterval<T> {
+    type Error = NonNegativeError;
+
+    fn try_from(value: NonNegative<T>) -> Result<Self, Self::Error> {
+        Self::try_new(value.get())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_try_new() {
+        assert_eq!(UnitInterval::try_new(0.25f64).unwrap().get(), 0.25);
+        assert_eq!(UnitInterval::try_new(1.0f64).unwrap().get(), 1.0);
+        assert!(
+            UnitInterval::try_new(-0.0f64)
+                .unwrap()
+                .get()
+                .is_sign_positive()
+        );
+        assert_eq!(
+            UnitInterval::try_new(1.5f64).unwrap_err(),
+            NonNegativeError::GreaterThanOne(1.5)
+        );
+        assert_eq!(
+            UnitInterval::try_new(-0.5f32).unwrap_err(),
+            NonNegativeError::Negative(-0.5)
+        );
+        assert_eq!(
+            UnitInterval::try_new(f64::NAN).unwrap_err(),
+            NonNegativeError::NaN
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Value must be between 0 and 1")]
+    fn test_new_panics_above_one() {
+        let _ = UnitInterval::new(2.0f64);
+    }
+
+    #[test]
+    fn test_new_const() {
+        const HALF: UnitInterval<f64> = UnitInterval::<f64>::new_const(0.5);
+        assert_eq!(HALF.get(), 0.5);
+    }
+
+    #[test]
+    fn test_complement_and_mul() {
+        let p = UnitInterval::new(0.25f64);
+        assert_eq!(p.complement().get(), 0.75);
+        assert_eq!(
+            UnitInterval::<f64>::one().complement(),
+            UnitInterval::zero()
+        );
+        assert_eq!((p * p.complement()).get(), 0.1875);
+
+        let mut q = UnitInterval::new(0.5f64);
+        q *= p;
+        assert_eq!(q.get(), 0.125);
+    }
+
+    #[test]
+    fn test_conversions() {
+        let p = UnitInterval::new(0.5f64);
+        let n: NonNegative<f64> = p.into();
+        assert_eq!(n.get(), 0.5);
+        assert_eq!(UnitInterval::try_from(n).unwrap(), p);
+        assert_eq!(
+            UnitInterval::try_from(NonNegative::new(3.0f64)).unwrap_err(),
+            NonNegativeError::GreaterThanOne(3.0)
+        );
+        assert_eq!((NonNegative::new(8.0f64) * p).get(), 4.0);
+    }
+}
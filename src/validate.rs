@@ -0,0 +1,103 @@
+//! Fast bulk validation of `f32` and `f64` slices.
+//!
+//! A non-negative finite float has a clear sign bit and an exponent that is
+//! not all ones, which is exactly when its bit pattern, read as an unsigned
+//! integer, is below that of `+inf`. Checking a whole chunk is then a single
+//! integer comparison per lane, which the compiler vectorises on stable.
+
+use crate::{NonNegative, SliceError};
+
+/// Elements checked per branch. Large enough to fill several vector
+/// registers on common targets.
+const CHUNK: usize = 64;
+
+macro_rules! impl_validate_slice {
+    ($($t:ty),*) => {$(
+        impl NonNegative<$t> {
+            /// Views a slice of raw floats as non-negative values, without
+            /// copying, using a vectorised check.
+            ///
+            /// Behaves like [`try_from_slice`](crate::Bounded::try_from_slice):
+            /// returns `Err` with the first invalid index, and rejects
+            /// negative zero.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// use nonneg_float::NonNegative;
+            ///
+            /// let readings = [0.5f32, 1.0, 2.0];
+            /// let valid = NonNegative::<f32>::validate_slice(&readings).unwrap();
+            /// assert_eq!(valid[2].get(), 2.0);
+            ///
+            /// let err = NonNegative::<f32>::validate_slice(&[1.0, -1.0]).unwrap_err();
+            /// assert_eq!(err.index, 1);
+            /// ```
+            pub fn validate_slice(values: &[$t]) -> Result<&[Self], SliceError> {
+                const LIMIT: u64 = <$t>::INFINITY.to_bits() as u64;
+                for (chunk_index, chunk) in values.chunks(CHUNK).enumerate() {
+                    let invalid = chunk
+                        .iter()
+                        .fold(false, |acc, value| acc | (value.to_bits() as u64 >= LIMIT));
+                    // Only a flagged chunk pays for the scalar scan that
+                    // builds the error.
+                    if invalid && let Err(err) = Self::try_from_slice(chunk) {
+                        return Err(SliceError {
+                            index: chunk_index * CHUNK + err.index,
+                            ..err
+                        });
+                    }
+                }
+                // SAFETY: `NonNegative<T>` is `repr(transparent)` over `T`,
+                // and every element was checked to uphold the invariant.
+                Ok(unsafe { core::slice::from_raw_parts(values.as_ptr().cast::<Self>(), values.len()) })
+            }
+        }
+    )*};
+}
+
+impl_validate_slice!(f32, f64);
+
+#[cfg(test)]
+mod tests {
+    use crate::{NonNegative, NonNegativeError};
+
+    #[test]
+    fn test_validate_slice() {
+        let values: [f64; 200] = core::array::from_fn(|i| i as f64 * 0.25);
+        let valid = NonNegative::<f64>::validate_slice(&values).unwrap();
+        assert_eq!(valid.len(), 200);
+        assert_eq!(valid[199].get(), 49.75);
+        assert!(NonNegative::<f32>::validate_slice(&[]).unwrap().is_empty());
+        assert_eq!(
+            NonNegative::<f32>::validate_slice(&[0.0, f32::MAX]).unwrap()[1].get(),
+            f32::MAX
+        );
+    }
+
+    #[test]
+    fn test_reports_first_invalid_index() {
+        let mut values = [1.0f32; 150];
+        values[140] = -2.0;
+        values[130] = f32::NAN;
+        let err = NonNegative::<f32>::validate_slice(&values).unwrap_err();
+        assert_eq!(err.index, 130);
+        assert_eq!(err.error, NonNegativeError::NaN);
+
+        values[70] = f32::NEG_INFINITY;
+        let err = NonNegative::<f32>::validate_slice(&values).unwrap_err();
+        assert_eq!(err.index, 70);
+        assert_eq!(err.error, NonNegativeError::Infinite(f64::NEG_INFINITY));
+    }
+
+    #[test]
+    fn test_matches_try_from_slice() {
+        for bad in [-0.0, -1e-300, f64::INFINITY, f64::NAN, -f64::MAX] {
+            let values = [3.0, bad, 1.0];
+            assert_eq!(
+                NonNegative::<f64>::validate_slice(&values),
+                NonNegative::<f64>::try_from_slice(&values)
+            );
+        }
+    }
+}
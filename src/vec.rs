@@ -0,0 +1,322 @@
+//! A validated, owned collection of non-negative values.
+
+use crate::{NonNegative, NonNegativeError, SliceError};
+use alloc::vec::Vec;
+use core::fmt;
+use core::hash::{Hash, Hasher};
+use core::mem::ManuallyDrop;
+use core::ops::Deref;
+use num_traits::Float;
+
+/// Number of independent accumulators used by the reductions, so they can be
+/// vectorised without reassociating float additions.
+const LANES: usize = 8;
+
+/// Error type returned when a `Vec` contains invalid elements, listing every
+/// offending index.
+#[derive(Debug, Clone, PartialEq)]
+pub struct NonNegativeVecError {
+    invalid: Vec<SliceError>,
+}
+
+impl NonNegativeVecError {
+    /// Returns every invalid element, in index order.
+    pub fn invalid(&self) -> &[SliceError] {
+        &self.invalid
+    }
+
+    /// Returns the indices of the invalid elements, in order.
+    pub fn indices(&self) -> impl Iterator<Item = usize> + '_ {
+        self.invalid.iter().map(|err| err.index)
+    }
+}
+
+impl fmt::Display for NonNegativeVecError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let first = &self.invalid[0];
+        write!(
+            f,
+            "{} invalid elements, first at index {}: {}",
+            self.invalid.len(),
+            first.index,
+            first.error
+        )
+    }
+}
+
+impl core::error::Error for NonNegativeVecError {}
+
+/// An owned `Vec` whose elements are all non-negative and finite.
+///
+/// Built from a `Vec<T>` in a single validation pass that reuses the
+/// allocation, and derefs to `&[NonNegative<T>]`.
+///
+/// # Examples
+///
+/// ```
+/// use nonneg_float::NonNegativeVec;
+///
+/// let mut weights = NonNegativeVec::try_from_vec(vec![1.0, 3.0, 4.0]).unwrap();
+/// assert_eq!(weights.sum().unwrap().get(), 8.0);
+/// assert_eq!(weights.max().unwrap().get(), 4.0);
+///
+/// weights.normalize().unwrap();
+/// assert_eq!(weights.as_floats(), [0.125, 0.375, 0.5]);
+///
+/// let err = NonNegativeVec::try_from_vec(vec![1.0, -2.0, f64::NAN]).unwrap_err();
+/// assert_eq!(err.indices().collect::<Vec<_>>(), [1, 2]);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct NonNegativeVec<T: Float> {
+    values: Vec<NonNegative<T>>,
+}
+
+// Implemented by hand, as deriving would require `T: Eq + Hash`, which
+// floats never satisfy.
+impl<T: Float> Eq for NonNegativeVec<T> {}
+
+impl<T: Float> Hash for NonNegativeVec<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.values.hash(state);
+    }
+}
+
+impl<T: Float> NonNegativeVec<T> {
+    /// Creates an empty `NonNegativeVec`.
+    pub fn new() -> Self {
+        Self { values: Vec::new() }
+    }
+
+    /// Validates every element of `values`, reusing its allocation.
+    ///
+    /// Negative zero is normalised to `0.0`. Returns `Err` listing every
+    /// invalid element.
+    pub fn try_from_vec(mut values: Vec<T>) -> Result<Self, NonNegativeVecError> {
+        let mut invalid = Vec::new();
+        for (index, value) in values.iter_mut().enumerate() {
+            match NonNegative::try_new(*value) {
+                Ok(valid) => *value = valid.get(),
+                Err(error) => invalid.push(SliceError { index, error }),
+            }
+        }
+        if !invalid.is_empty() {
+            return Err(NonNegativeVecError { invalid });
+        }
+
+        let mut values = ManuallyDrop::new(values);
+        let (ptr, len, capacity) = (values.as_mut_ptr(), values.len(), values.capacity());
+        // SAFETY: `NonNegative<T>` is `repr(transparent)` over `T`, so the
+        // allocation layout is unchanged, and every element was validated.
+        let values = unsafe { Vec::from_raw_parts(ptr.cast::<NonNegative<T>>(), len, capacity) };
+        Ok(Self { values })
+    }
+
+    /// Returns the elements as raw floats.
+    pub fn as_floats(&self) -> &[T] {
+        NonNegative::as_slice_of_floats(&self.values)
+    }
+
+    /// Consumes the collection, returning the validated elements.
+    pub fn into_inner(self) -> Vec<NonNegative<T>> {
+        self.values
+    }
+
+    /// Appends a value.
+    pub fn push(&mut self, value: NonNegative<T>) {
+        self.values.push(value);
+    }
+
+    /// Sums the elements.
+    ///
+    /// Returns `Err` if the sum overflows to infinity.
+    pub fn sum(&self) -> Result<NonNegative<T>, NonNegativeError> {
+        let floats = self.as_floats();
+        let mut lanes = [T::zero(); LANES];
+        let mut chunks = floats.chunks_exact(LANES);
+        for chunk in &mut chunks {
+            for (lane, &value) in lanes.iter_mut().zip(chunk) {
+                *lane = *lane + value;
+            }
+        }
+        let total = chunks
+            .remainder()
+            .iter()
+            .chain(&lanes)
+            .fold(T::zero(), |acc, &value| acc + value);
+        NonNegative::try_new(total)
+    }
+
+    /// Returns the largest element, or `None` if empty.
+    pub fn max(&self) -> Option<NonNegative<T>> {
+        self.reduce(|a, b| if b > a { b } else { a })
+    }
+
+    /// Returns the smallest element, or `None` if empty.
+    pub fn min(&self) -> Option<NonNegative<T>> {
+        self.reduce(|a, b| if b < a { b } else { a })
+    }
+
+    /// Scales the elements in place so that they sum to one.
+    ///
+    /// Returns `Err` if the sum is zero or overflows, leaving the elements
+    /// unchanged.
+    pub fn normalize(&mut self) -> Result<(), NonNegativeError> {
+        let total = self.sum()?.get();
+        if total == T::zero() {
+            return Err(NonNegativeError::Zero);
+        }
+        for value in &mut self.values {
+            *value = NonNegative::new_unchecked(value.get() / total);
+        }
+        Ok(())
+    }
+
+    fn reduce(&self, pick: impl Fn(T, T) -> T) -> Option<NonNegative<T>> {
+        let floats = self.as_floats();
+        let first = *floats.first()?;
+        let mut lanes = [first; LANES];
+        let mut chunks = floats.chunks_exact(LANES);
+        for chunk in &mut chunks {
+            for (lane, &value) in lanes.iter_mut().zip(chunk) {
+                *lane = pick(*lane, value);
+            }
+        }
+        let best = chunks
+            .remainder()
+            .iter()
+            .chain(&lanes)
+            .fold(first, |acc, &value| pick(acc, value));
+        Some(NonNegative::new_unchecked(best))
+    }
+}
+
+impl<T: Float> Deref for NonNegativeVec<T> {
+    type Target = [NonNegative<T>];
+
+    fn deref(&self) -> &Self::Target {
+        &self.values
+    }
+}
+
+impl<T: Float> AsRef<[NonNegative<T>]> for NonNegativeVec<T> {
+    fn as_ref(&self) -> &[NonNegative<T>] {
+        &self.values
+    }
+}
+
+impl<T: Float> TryFrom<Vec<T>> for NonNegativeVec<T> {
+    type Error = NonNegativeVecError;
+
+    fn try_from(values: Vec<T>) -> Result<Self, Self::Error> {
+        Self::try_from_vec(values)
+    }
+}
+
+impl<T: Float> From<Vec<NonNegative<T>>> for NonNegativeVec<T> {
+    fn from(values: Vec<NonNegative<T>>) -> Self {
+        Self { values }
+    }
+}
+
+impl<T: Float> From<NonNegativeVec<T>> for Vec<NonNegative<T>> {
+    fn from(values: NonNegativeVec<T>) -> Self {
+        values.values
+    }
+}
+
+impl<T: Float> FromIterator<NonNegative<T>> for NonNegativeVec<T> {
+    fn from_iter<I: IntoIterator<Item = NonNegative<T>>>(iter: I) -> Self {
+        Self {
+            values: iter.into_iter().collect(),
+        }
+    }
+}
+
+impl<T: Float> IntoIterator for NonNegativeVec<T> {
+    type Item = NonNegative<T>;
+    type IntoIter = alloc::vec::IntoIter<NonNegative<T>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.values.into_iter()
+    }
+}
+
+impl<'a, T: Float> IntoIterator for &'a NonNegativeVec<T> {
+    type Item = &'a NonNegative<T>;
+    type IntoIter = core::slice::Iter<'a, NonNegative<T>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.values.iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+    use std::collections::HashSet;
+
+    #[test]
+    fn test_try_from_vec() {
+        let values = NonNegativeVec::try_from_vec(vec![0.5f64, -0.0, 2.0]).unwrap();
+        assert_eq!(values.len(), 3);
+        assert!(values[1].get().is_sign_positive());
+        assert_eq!(values.as_floats(), [0.5, 0.0, 2.0]);
+    }
+
+    #[test]
+    fn test_reports_all_invalid() {
+        let err = NonNegativeVec::try_from_vec(vec![1.0f32, -1.0, 2.0, f32::INFINITY]).unwrap_err();
+        assert_eq!(err.indices().collect::<Vec<_>>(), [1, 3]);
+        assert_eq!(err.invalid()[0].error, NonNegativeError::Negative(-1.0));
+        assert_eq!(
+            err.to_string(),
+            "2 invalid elements, first at index 1: Value must be non-negative, got -1"
+        );
+    }
+
+    #[test]
+    fn test_reductions() {
+        let values: Vec<f64> = (0..21).map(f64::from).collect();
+        let values = NonNegativeVec::try_from_vec(values).unwrap();
+        assert_eq!(values.sum().unwrap().get(), 210.0);
+        assert_eq!(values.max().unwrap().get(), 20.0);
+        assert_eq!(values.min().unwrap().get(), 0.0);
+
+        let empty = NonNegativeVec::<f64>::new();
+        assert_eq!(empty.sum().unwrap().get(), 0.0);
+        assert!(empty.max().is_none());
+        assert!(empty.min().is_none());
+
+        let huge = NonNegativeVec::try_from_vec(vec![f64::MAX; 2]).unwrap();
+        assert!(huge.sum().is_err());
+    }
+
+    #[test]
+    fn test_normalize() {
+        let mut values = NonNegativeVec::try_from_vec(vec![1.0f64, 1.0, 2.0]).unwrap();
+        values.normalize().unwrap();
+        assert_eq!(values.as_floats(), [0.25, 0.25, 0.5]);
+
+        let mut zeros = NonNegativeVec::try_from_vec(vec![0.0f64; 3]).unwrap();
+        assert_eq!(zeros.normalize(), Err(NonNegativeError::Zero));
+    }
+
+    #[test]
+    fn test_collect_and_iterate() {
+        let values: NonNegativeVec<f64> = [1.0, 2.0].map(NonNegative::new).into_iter().collect();
+        let total: NonNegative<f64> = values.iter().sum();
+        assert_eq!(total.get(), 3.0);
+        let inner: Vec<NonNegative<f64>> = values.into();
+        assert_eq!(inner.len(), 2);
+    }
+
+    #[test]
+    fn test_eq_and_hash() {
+        let a = NonNegativeVec::try_from_vec(vec![1.0f64, -0.0]).unwrap();
+        let b = NonNegativeVec::try_from_vec(vec![1.0f64, 0.0]).unwrap();
+        let c = NonNegativeVec::try_from_vec(vec![2.0f64]).unwrap();
+        let set: HashSet<_> = [a, b, c].into_iter().collect();
+        assert_eq!(set.len(), 2);
+    }
+}
@@ -0,0 +1,7 @@
+use nonneg_float::NonNegative;
+
+const LIMIT: NonNegative<f64> = NonNegative::<f64>::new_const(f64::INFINITY);
+
+fn main() {
+    let _ = LIMIT;
+}
@@ -0,0 +1,7 @@
+use nonneg_float::NonNegative;
+
+const RATE: NonNegative<f32> = NonNegative::<f32>::new_const(f32::NAN);
+
+fn main() {
+    let _ = RATE;
+}
@@ -0,0 +1,5 @@
+use nonneg_float::nonneg;
+
+fn main() {
+    let _ = nonneg!(f32, -0.5);
+}
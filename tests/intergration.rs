@@ -1,15 +1,20 @@
-use nonneg_float::{NonNegative, nonneg};
+use nonneg_float::{NonNegative, NonNegativeError, nonneg};
 
 #[test]
 fn test_valid_values() {
     let n = nonneg!(42.0f64);
-    assert_eq!(n.unwrap().get(), 42.0);
+    assert_eq!(n.get(), 42.0);
+
+    let value = 21.0f32;
+    let m = nonneg!(value * 2.0);
+    assert_eq!(m.unwrap().get(), 42.0);
 }
 
 #[test]
 fn test_invalid_value() {
-    let result = nonneg!(-1.0f64);
-    assert!(result.is_err());
+    let value = 1.0f64;
+    let result = nonneg!(-value);
+    assert_eq!(result.unwrap_err(), NonNegativeError::Negative(-1.0));
 }
 
 #[test]
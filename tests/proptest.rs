@@ -0,0 +1,70 @@
+#![cfg(feature = "proptest")]
+
+use nonneg_float::strategy::{any_nonneg, nonneg_in, nonneg_up_to};
+use nonneg_float::{NonNegative, NonNegativeError};
+use proptest::prelude::*;
+
+proptest! {
+    #[test]
+    fn try_new_accepts_exactly_non_negative_finite(value in any::<f64>()) {
+        match NonNegative::try_new(value) {
+            Ok(n) => {
+                prop_assert!(value.is_finite() && value >= 0.0);
+                prop_assert_eq!(n.get(), value);
+                prop_assert!(n.get().is_sign_positive());
+            }
+            Err(NonNegativeError::NaN) => prop_assert!(value.is_nan()),
+            Err(NonNegativeError::Infinite(v)) => prop_assert_eq!(v, value),
+            Err(NonNegativeError::Negative(v)) => {
+                prop_assert!(value < 0.0);
+                prop_assert_eq!(v, value);
+            }
+            Err(err) => prop_assert!(false, "unexpected error {:?}", err),
+        }
+    }
+
+    #[test]
+    fn try_new_f32_matches_f64(value in any::<f32>()) {
+        let narrow = NonNegative::try_new(value).map(|n| f64::from(n.get()));
+        let wide = NonNegative::try_new(f64::from(value)).map(|n| n.get());
+        prop_assert_eq!(narrow.is_ok(), wide.is_ok());
+        if let (Ok(a), Ok(b)) = (narrow, wide) {
+            prop_assert_eq!(a, b);
+        }
+    }
+
+    #[test]
+    fn generated_values_revalidate(n in any_nonneg::<f64>(), m in any_nonneg::<f32>()) {
+        prop_assert_eq!(NonNegative::try_new(n.get()), Ok(n));
+        prop_assert_eq!(NonNegative::try_new(m.get()), Ok(m));
+        prop_assert!(n.get().is_sign_positive());
+    }
+
+    #[test]
+    fn ranged_values_stay_in_range(n in nonneg_in(2.0f64..=3.0), m in nonneg_up_to(1e-30f32)) {
+        prop_assert!((2.0..=3.0).contains(&n.get()));
+        prop_assert!(m.get() <= 1e-30);
+    }
+
+    #[test]
+    fn checked_add_fails_only_on_overflow(a in any_nonneg::<f64>(), b in any_nonneg::<f64>()) {
+        let raw = a.get() + b.get();
+        match a.checked_add(b) {
+            Some(sum) => prop_assert_eq!(sum.get(), raw),
+            None => prop_assert!(raw.is_infinite()),
+        }
+    }
+
+    #[test]
+    fn display_round_trips(n in any_nonneg::<f64>()) {
+        prop_assert_eq!(n.to_string().parse::<NonNegative<f64>>(), Ok(n));
+    }
+
+    #[test]
+    fn validate_slice_matches_try_from_slice(values in prop::collection::vec(any::<f64>(), 0..200)) {
+        prop_assert_eq!(
+            NonNegative::<f64>::validate_slice(&values),
+            NonNegative::<f64>::try_from_slice(&values)
+        );
+    }
+}
@@ -0,0 +1,111 @@
+#![cfg(feature = "serde")]
+
+use nonneg_float::{Bounded, Bounds, NonNegative, Positive, UnitInterval};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Config {
+    rate: NonNegative<f64>,
+    scale: NonNegative<f32>,
+}
+
+#[test]
+fn test_json_round_trip() {
+    let config = Config {
+        rate: NonNegative::new(0.25),
+        scale: NonNegative::new(2.0),
+    };
+    let json = serde_json::to_string(&config).unwrap();
+    assert_eq!(json, r#"{"rate":0.25,"scale":2.0}"#);
+    assert_eq!(serde_json::from_str::<Config>(&json).unwrap(), config);
+}
+
+#[test]
+fn test_json_rejects_negative() {
+    let err = serde_json::from_str::<NonNegative<f64>>("-3.0").unwrap_err();
+    let msg = err.to_string();
+    assert!(msg.contains("-3"), "{msg}");
+    assert!(msg.contains("non-negative"), "{msg}");
+}
+
+#[test]
+fn test_toml_round_trip() {
+    let config = Config {
+        rate: NonNegative::new(1.5),
+        scale: NonNegative::zero(),
+    };
+    let text = toml::to_string(&config).unwrap();
+    assert_eq!(toml::from_str::<Config>(&text).unwrap(), config);
+}
+
+#[test]
+fn test_toml_rejects_invalid() {
+    for rate in ["-1.5", "nan", "inf", "-inf"] {
+        let text = format!("rate = {rate}\nscale = 1.0\n");
+        let err = toml::from_str::<Config>(&text).unwrap_err();
+        assert!(err.to_string().contains("non-negative"), "{err}");
+    }
+}
+
+#[test]
+fn test_bincode_round_trip() {
+    let value = NonNegative::new(42.0f64);
+    let bytes = bincode::serialize(&value).unwrap();
+    assert_eq!(bytes, bincode::serialize(&42.0f64).unwrap());
+    assert_eq!(
+        bincode::deserialize::<NonNegative<f64>>(&bytes).unwrap(),
+        value
+    );
+}
+
+#[test]
+fn test_bincode_rejects_invalid() {
+    for raw in [-0.5f64, f64::NAN, f64::INFINITY, f64::NEG_INFINITY] {
+        let bytes = bincode::serialize(&raw).unwrap();
+        assert!(bincode::deserialize::<NonNegative<f64>>(&bytes).is_err());
+    }
+}
+
+#[test]
+fn test_positive_round_trip() {
+    let value = Positive::new(0.5f64);
+    let json = serde_json::to_string(&value).unwrap();
+    assert_eq!(json, "0.5");
+    assert_eq!(serde_json::from_str::<Positive<f64>>(&json).unwrap(), value);
+}
+
+#[test]
+fn test_positive_rejects_zero() {
+    let err = serde_json::from_str::<Positive<f64>>("0.0").unwrap_err();
+    assert!(err.to_string().contains("positive"), "{err}");
+    assert!(serde_json::from_str::<Positive<f32>>("-1.0").is_err());
+}
+
+#[test]
+fn test_unit_interval_round_trip() {
+    let value = UnitInterval::new(0.75f64);
+    let json = serde_json::to_string(&value).unwrap();
+    assert_eq!(
+        serde_json::from_str::<UnitInterval<f64>>(&json).unwrap(),
+        value
+    );
+    let err = serde_json::from_str::<UnitInterval<f64>>("1.5").unwrap_err();
+    assert!(err.to_string().contains("between 0 and 1"), "{err}");
+}
+
+struct LatitudeBounds;
+
+impl Bounds for LatitudeBounds {
+    const LOWER: std::ops::Bound<f64> = std::ops::Bound::Included(-90.0);
+    const UPPER: std::ops::Bound<f64> = std::ops::Bound::Included(90.0);
+    const EXPECTING: &'static str = "a latitude in [-90, 90]";
+}
+
+#[test]
+fn test_custom_bounds() {
+    let value: Bounded<f64, LatitudeBounds> = serde_json::from_str("-45.5").unwrap();
+    assert_eq!(value.get(), -45.5);
+    assert_eq!(serde_json::to_string(&value).unwrap(), "-45.5");
+    let err = serde_json::from_str::<Bounded<f64, LatitudeBounds>>("91.0").unwrap_err();
+    assert!(err.to_string().contains("a latitude in [-90, 90]"), "{err}");
+}